@@ -1,7 +1,10 @@
 use anyhow::Context as _;
 use schemars::JsonSchema;
 use serde::Deserialize;
-use std::{collections::HashMap, path::Path};
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::Path,
+};
 
 /// The main application config.
 #[derive(Debug, Clone, Deserialize, JsonSchema, PartialEq, Eq)]
@@ -14,6 +17,10 @@ pub struct Config {
     /// Configuration for the shell commands executor used when rewriting cgroup names with `Templated::Shell`.
     #[serde(default, rename = "shell")]
     pub shell_commands: ShellCommandsConfig,
+    /// Labels to add to every metric. The values can include environment variables such as
+    /// `${NODE_NAME}`, and `{hostname}` for the hostname of the machine.
+    #[serde(default)]
+    pub labels: BTreeMap<String, String>,
 }
 
 /// The config for a cgroup. This includes the matcher and the metrics config.
@@ -47,11 +54,130 @@ pub struct MetricsConfig {
     #[serde(default = "default_label_map")]
     /// The label map to use for the metrics. This is used to rename labels in the metrics.
     /// For example, if the label map is `{"name": "process"}`, the label "name" will be renamed to "process".
+    /// Only the `name` label can be renamed, use `relabel` for the other labels.
     pub label_map: HashMap<String, String>,
     /// The namespace to use for the metrics. This is used to group metrics together.
     /// For example, if the namespace is `my_service`, the metrics will be prefixed with `my_service_`
     /// instead of `process_` or `cgroup_`.
     pub namespace: Option<String>,
+    /// Export the CPU usage of cgroups per CPU and mode from the v1 `cpuacct` controller.
+    /// This adds two series per CPU for each cgroup, so it is disabled by default. The series
+    /// replace the total `cpuacct_usage_seconds_total` of the cgroup.
+    #[serde(default)]
+    pub per_cpu_usage: bool,
+    /// Export the working set, RSS, cache and mapped file memory of cgroups, computed the same way
+    /// as cAdvisor on both cgroup versions.
+    #[serde(default)]
+    pub derived_memory: bool,
+    /// Export the network interface counters of cgroups, read from the network namespace of their
+    /// processes. Cgroups in the host network namespace are skipped.
+    #[serde(default)]
+    pub network: bool,
+    /// Read the PSS, USS, swap and shared memory of processes from `/proc/<pid>/smaps_rollup`,
+    /// and use PSS for the memory usage. Reading it walks all the memory mappings of each
+    /// process, so it is disabled by default.
+    #[serde(default)]
+    pub smaps_rollup: bool,
+    /// Count the TCP and UDP sockets of processes by protocol and state. This reads the link of
+    /// every fd of each process and the socket tables of their network namespaces, so it is
+    /// disabled by default.
+    #[serde(default)]
+    pub sockets: bool,
+    /// Count the fds of processes by the type of their target. This reads the link of every fd
+    /// of each process, so it is disabled by default.
+    #[serde(default)]
+    pub fd_types: bool,
+    /// Export the `*_usec` CPU counters of cgroups as `*_seconds_total` counters in seconds
+    /// instead.
+    #[serde(default)]
+    pub usec_as_seconds: bool,
+    /// Labels to add to the metrics of this matcher, expanded the same way as the top-level
+    /// `labels`.
+    #[serde(default)]
+    pub labels: BTreeMap<String, String>,
+    /// Relabeling rules applied in order to the label set of each series, like Prometheus
+    /// `relabel_configs`.
+    #[serde(default)]
+    pub relabel: Vec<RelabelConfig>,
+    /// Globs over the metric names without the namespace, such as `memory_*`. When set, only
+    /// the matching metrics are exported, and the controllers that none of them need are not
+    /// read.
+    #[serde(default)]
+    pub include: Vec<String>,
+    /// Globs over the metric names without the namespace to leave out, applied after `include`.
+    #[serde(default)]
+    pub exclude: Vec<String>,
+    /// The controllers to read for cgroups. All of them are read when this is not set. It has no
+    /// effect on process matchers.
+    #[serde(default)]
+    pub controllers: Option<Vec<Controller>>,
+}
+
+/// A source of cgroup metrics that can be turned off with `metrics.controllers`.
+#[derive(Debug, Clone, Copy, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Controller {
+    Memory,
+    Cpu,
+    /// The cgroup v1 CPU accounting controller.
+    CpuAcct,
+    /// The `blkio` controller on cgroup v1, or `io` on cgroup v2.
+    Io,
+    HugeTlb,
+    Pids,
+    /// The core `cgroup.*` files on cgroup v2.
+    Core,
+    /// The pressure stall information on cgroup v2.
+    Pressure,
+    /// The per-process metrics summed over all the processes in the cgroup.
+    Processes,
+}
+
+/// A relabeling rule, modelled on Prometheus `relabel_configs`.
+#[derive(Debug, Clone, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RelabelConfig {
+    /// The labels whose values are joined with the separator and matched against the regex.
+    #[serde(default)]
+    pub source_labels: Vec<String>,
+    /// The separator between the values of the source labels.
+    #[serde(default = "default_relabel_separator")]
+    pub separator: String,
+    /// The regex matched against the joined source values, or against the label names for
+    /// `labelmap` and `labeldrop`. It is anchored on both ends.
+    #[serde(default = "default_relabel_regex")]
+    pub regex: String,
+    /// The modulus for `hashmod`.
+    #[serde(default)]
+    pub modulus: Option<u64>,
+    /// The label to write for `replace` and `hashmod`. It can refer to the regex capture groups.
+    #[serde(default)]
+    pub target_label: Option<String>,
+    /// The value written for `replace`, or the label name for `labelmap`. It can refer to the
+    /// regex capture groups, e.g. `$1` or `${name}`.
+    #[serde(default = "default_relabel_replacement")]
+    pub replacement: String,
+    /// The action to perform.
+    #[serde(default)]
+    pub action: RelabelAction,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, JsonSchema, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum RelabelAction {
+    /// Set the target label to the replacement if the regex matches.
+    #[default]
+    Replace,
+    /// Drop the series if the regex doesn't match.
+    Keep,
+    /// Drop the series if the regex matches.
+    Drop,
+    /// Copy the labels whose names match the regex to the label named by the replacement.
+    LabelMap,
+    /// Remove the labels whose names match the regex.
+    LabelDrop,
+    /// Set the target label to a hash of the source values, modulo `modulus`.
+    HashMod,
 }
 
 /// A matcher for cgroups. It can match a single cgroup or a group of cgroups.
@@ -63,6 +189,10 @@ pub struct CgroupMatch {
     /// Group name rewrite rules.
     #[serde(flatten, default)]
     pub rewrite: Option<RewriteCgroupName>,
+    /// Extra labels to add to the metrics, each one rendered from the regex capture groups like
+    /// the name. For example, `{ service: "{serviceName}", instance: "{instance}" }`.
+    #[serde(default)]
+    pub labels: BTreeMap<String, String>,
 }
 
 #[derive(Debug, Clone, Deserialize, JsonSchema, PartialEq, Eq)]
@@ -131,6 +261,9 @@ pub enum ProcessMatch {
         exe: NameMatch,
         /// The path to use for the whole match group. This can use template variables to divide the group into subgroups.
         name: String,
+        /// Extra labels to add to the metrics. These can use the same template variables as the name.
+        #[serde(default)]
+        labels: BTreeMap<String, String>,
     },
     /// Match a process by its executable name.
     ExeBase {
@@ -138,6 +271,9 @@ pub enum ProcessMatch {
         exe_base: NameMatch,
         /// The name to use for the whole match group. This can use template variables to divide the group into subgroups.
         name: String,
+        /// Extra labels to add to the metrics. These can use the same template variables as the name.
+        #[serde(default)]
+        labels: BTreeMap<String, String>,
     },
     /// Match a process by its command name.
     Comm {
@@ -145,6 +281,9 @@ pub enum ProcessMatch {
         comm: NameMatch,
         /// The name to use for the whole match group. This can use template variables to divide the group into subgroups.
         name: String,
+        /// Extra labels to add to the metrics. These can use the same template variables as the name.
+        #[serde(default)]
+        labels: BTreeMap<String, String>,
     },
     /// Match a process by its whole command line.
     Cmdline {
@@ -152,6 +291,9 @@ pub enum ProcessMatch {
         cmdline: NameMatch,
         /// The name to use for the whole match group. This can use template variables to divide the group into subgroups.
         name: String,
+        /// Extra labels to add to the metrics. These can use the same template variables as the name.
+        #[serde(default)]
+        labels: BTreeMap<String, String>,
     },
 }
 
@@ -174,6 +316,28 @@ fn default_cache_size() -> usize {
     100
 }
 
+fn default_relabel_separator() -> String {
+    ";".to_string()
+}
+
+fn default_relabel_regex() -> String {
+    "(.*)".to_string()
+}
+
+fn default_relabel_replacement() -> String {
+    "$1".to_string()
+}
+
+impl MetricsConfig {
+    /// Returns whether the controller is read, according to `controllers`.
+    #[must_use]
+    pub fn reads(&self, controller: Controller) -> bool {
+        self.controllers
+            .as_ref()
+            .is_none_or(|controllers| controllers.contains(&controller))
+    }
+}
+
 impl Config {
     /// Creates a new `Config` from a YAML string.
     ///
@@ -220,6 +384,28 @@ mod tests {
     use pretty_assertions::assert_eq;
     use std::path::Path;
 
+    #[test]
+    fn reads_selected_controllers() {
+        let all = MetricsConfig::default();
+        assert!(all.reads(Controller::Memory));
+        assert!(all.reads(Controller::Processes));
+
+        let selected = MetricsConfig {
+            controllers: Some(vec![Controller::Memory, Controller::Io]),
+            ..MetricsConfig::default()
+        };
+        assert!(selected.reads(Controller::Memory));
+        assert!(selected.reads(Controller::Io));
+        assert!(!selected.reads(Controller::Cpu));
+        assert!(!selected.reads(Controller::Processes));
+
+        let none = MetricsConfig {
+            controllers: Some(vec![]),
+            ..MetricsConfig::default()
+        };
+        assert!(!none.reads(Controller::Memory));
+    }
+
     #[test]
     fn test_load_config1() {
         let config = load_config_blocking(Path::new("../tests/test_data/config1.yml")).unwrap();
@@ -234,13 +420,36 @@ mod tests {
                             path: NameMatch::Glob("services.scope/*".to_string()),
                             rewrite: Some(RewriteCgroupName::RemovePrefix {
                                 remove_prefix: "services.scope/".to_string()
-                            })
+                            }),
+                            labels: BTreeMap::new(),
                         },
                         metrics: MetricsConfig {
                             label_map: vec![("name".to_string(), "name".to_string())]
                                 .into_iter()
                                 .collect(),
-                            namespace: Some("my_services".to_string())
+                            namespace: Some("my_services".to_string()),
+                            per_cpu_usage: true,
+                            derived_memory: false,
+                            network: false,
+                            smaps_rollup: false,
+                            sockets: false,
+                            fd_types: false,
+                            usec_as_seconds: false,
+                            labels: [("env".to_string(), "prod".to_string())]
+                                .into_iter()
+                                .collect(),
+                            relabel: vec![RelabelConfig {
+                                source_labels: vec!["name".to_string()],
+                                separator: ";".to_string(),
+                                regex: "(.*)-worker".to_string(),
+                                modulus: None,
+                                target_label: Some("role".to_string()),
+                                replacement: "worker".to_string(),
+                                action: RelabelAction::Replace,
+                            }],
+                            include: vec![],
+                            exclude: vec!["blkio_*".to_string(), "memory_numa_*".to_string()],
+                            controllers: None,
                         }
                     },
                     CgroupConfig {
@@ -254,13 +463,26 @@ mod tests {
                                     shell: "docker ps --filter \"id={containerId}\" --format \"{{.Names}}\"".to_string(),
                                     output: ShellCommandStream::Stdout,
                                 }
-                            })
+                            }),
+                            labels: BTreeMap::new(),
                         },
                         metrics: MetricsConfig {
                             label_map: vec![("name".to_string(), "name".to_string())]
                                 .into_iter()
                                 .collect(),
-                            namespace: Some("container".to_string())
+                            namespace: Some("container".to_string()),
+                            per_cpu_usage: false,
+                            derived_memory: true,
+                            network: true,
+                            smaps_rollup: false,
+                            sockets: false,
+                            fd_types: false,
+                            usec_as_seconds: true,
+                            labels: BTreeMap::new(),
+                            relabel: vec![],
+                            include: vec![],
+                            exclude: vec![],
+                            controllers: None,
                         }
                     },
                     CgroupConfig {
@@ -274,30 +496,71 @@ mod tests {
                                     name: Templated::Name("{containerId}".to_string())
                                 },
                             ),
+                            labels: [
+                                ("container_id".to_string(), "{containerId}".to_string()),
+                                ("runtime".to_string(), "docker".to_string()),
+                            ]
+                            .into_iter()
+                            .collect(),
                         },
                         metrics: MetricsConfig {
                             label_map: vec![("name".to_string(), "id".to_string())]
                                 .into_iter()
                                 .collect(),
-                            namespace: Some("container".to_string())
+                            namespace: Some("container".to_string()),
+                            per_cpu_usage: false,
+                            derived_memory: false,
+                            network: false,
+                            smaps_rollup: false,
+                            sockets: false,
+                            fd_types: false,
+                            usec_as_seconds: false,
+                            labels: BTreeMap::new(),
+                            relabel: vec![],
+                            include: vec![],
+                            exclude: vec![],
+                            controllers: Some(vec![
+                                Controller::Memory,
+                                Controller::Cpu,
+                                Controller::Pids,
+                            ]),
                         }
                     }
                 ],
                 processes: vec![ProcessConfig {
                     match_by: ProcessMatch::Comm {
                         comm: NameMatch::Glob("firefox".to_string()),
-                        name: "firefox".to_string()
+                        name: "firefox".to_string(),
+                        labels: BTreeMap::new(),
                     },
                     metrics: MetricsConfig {
                         label_map: vec![("name".to_string(), "name".to_string())]
                             .into_iter()
                             .collect(),
-                        namespace: Some("my_services".to_string())
+                        namespace: Some("my_services".to_string()),
+                        per_cpu_usage: false,
+                        derived_memory: false,
+                        network: false,
+                        smaps_rollup: true,
+                        sockets: true,
+                        fd_types: true,
+                        usec_as_seconds: false,
+                        labels: BTreeMap::new(),
+                        relabel: vec![],
+                        include: vec![],
+                        exclude: vec![],
+                        controllers: None,
                     }
                 }],
                 shell_commands: ShellCommandsConfig {
                     cache_size: 1024
-                }
+                },
+                labels: [
+                    ("host".to_string(), "{hostname}".to_string()),
+                    ("node".to_string(), "${NODE_NAME}".to_string()),
+                ]
+                .into_iter()
+                .collect(),
             }
         );
     }
@@ -0,0 +1,367 @@
+use std::{collections::BTreeMap, path::Path};
+
+use cgroups_rs::blkio::{BlkIo, BlkIoData, IoService, IoStat};
+use serde::Serialize;
+
+use crate::{cgroups::metrics::CgroupSeries, render::LabeledSeries};
+
+const MAJOR_LABEL: &str = "major";
+const MINOR_LABEL: &str = "minor";
+const DEVICE_LABEL: &str = "device";
+
+/// Where the kernel links each block device by its `major:minor` number.
+const SYS_DEV_BLOCK: &str = "/sys/dev/block";
+
+/// The `major` and `minor` numbers of a block device.
+type DeviceNumbers = (i16, i16);
+
+/// The blkio metrics that aggregate all the devices, from the v1 blkio files. The per-device
+/// entries are emitted as separate series, see [`blkio_metrics`].
+#[derive(Serialize, Default)]
+pub struct BlkIoTotals {
+    pub io_merged_total: u64,
+    pub io_merged_recursive_total: u64,
+    pub io_queued_total: u64,
+    pub io_queued_recursive_total: u64,
+    pub io_service_bytes_total: u64,
+    pub io_service_bytes_recursive_total: u64,
+    pub io_serviced_total: u64,
+    pub io_serviced_recursive_total: u64,
+    pub io_service_time_total: u64,
+    pub io_service_time_recursive_total: u64,
+    pub io_wait_time_total: u64,
+    pub io_wait_time_recursive_total: u64,
+    pub leaf_weight: u64,
+    pub weight: u64,
+    pub throttle: ThrottleTotals,
+}
+
+#[derive(Serialize, Default)]
+#[allow(clippy::struct_field_names)]
+pub struct ThrottleTotals {
+    pub io_service_bytes_total: u64,
+    pub io_service_bytes_recursive_total: u64,
+    pub io_serviced_total: u64,
+    pub io_serviced_recursive_total: u64,
+}
+
+/// The blkio metrics of a single device. Covers both the v1 blkio files and the v2 `io.stat`.
+#[derive(Serialize, Default)]
+pub struct BlkIoDevice {
+    pub io_merged: Option<IoOps>,
+    pub io_merged_recursive: Option<IoOps>,
+    pub io_queued: Option<IoOps>,
+    pub io_queued_recursive: Option<IoOps>,
+    pub io_service_bytes: Option<IoOps>,
+    pub io_service_bytes_recursive: Option<IoOps>,
+    pub io_serviced: Option<IoOps>,
+    pub io_serviced_recursive: Option<IoOps>,
+    pub io_service_time: Option<IoOps>,
+    pub io_service_time_recursive: Option<IoOps>,
+    pub io_wait_time: Option<IoOps>,
+    pub io_wait_time_recursive: Option<IoOps>,
+    pub leaf_weight_device: Option<u64>,
+    pub sectors: Option<u64>,
+    pub sectors_recursive: Option<u64>,
+    pub time: Option<u64>,
+    pub time_recursive: Option<u64>,
+    pub weight_device: Option<u64>,
+    pub throttle: Option<ThrottleDevice>,
+    pub io_stat: Option<IoStatOps>,
+}
+
+#[derive(Serialize, Default)]
+pub struct ThrottleDevice {
+    pub io_service_bytes: Option<IoOps>,
+    pub io_service_bytes_recursive: Option<IoOps>,
+    pub io_serviced: Option<IoOps>,
+    pub io_serviced_recursive: Option<IoOps>,
+    pub read_bps_device: Option<u64>,
+    pub read_iops_device: Option<u64>,
+    pub write_bps_device: Option<u64>,
+    pub write_iops_device: Option<u64>,
+}
+
+/// A v1 `IoService` entry without the device numbers. The per-device `Total` is left out so it
+/// doesn't collide with the aggregated totals.
+#[derive(Serialize, Default)]
+pub struct IoOps {
+    pub read: u64,
+    pub write: u64,
+    pub sync: u64,
+    pub r#async: u64,
+    pub discard: u64,
+}
+
+/// A v2 `io.stat` row without the device numbers.
+#[derive(Serialize, Default)]
+pub struct IoStatOps {
+    pub rbytes: u64,
+    pub wbytes: u64,
+    pub rios: u64,
+    pub wios: u64,
+    pub dbytes: u64,
+    pub dios: u64,
+}
+
+/// Splits the blkio metrics into the aggregated totals and one series per device.
+/// The series are labeled with the device numbers and the device name, e.g. `nvme0n1`.
+/// The totals are only read on cgroup v1, since v2 only has the per-device `io.stat`.
+pub fn blkio_metrics(
+    blkio: &BlkIo,
+    v2: bool,
+) -> (Option<BlkIoTotals>, Vec<LabeledSeries<CgroupSeries>>) {
+    let totals = (!v2).then(|| BlkIoTotals {
+        io_merged_total: blkio.io_merged_total,
+        io_merged_recursive_total: blkio.io_merged_recursive_total,
+        io_queued_total: blkio.io_queued_total,
+        io_queued_recursive_total: blkio.io_queued_recursive_total,
+        io_service_bytes_total: blkio.io_service_bytes_total,
+        io_service_bytes_recursive_total: blkio.io_service_bytes_recursive_total,
+        io_serviced_total: blkio.io_serviced_total,
+        io_serviced_recursive_total: blkio.io_serviced_recursive_total,
+        io_service_time_total: blkio.io_service_time_total,
+        io_service_time_recursive_total: blkio.io_service_time_recursive_total,
+        io_wait_time_total: blkio.io_wait_time_total,
+        io_wait_time_recursive_total: blkio.io_wait_time_recursive_total,
+        leaf_weight: blkio.leaf_weight,
+        weight: blkio.weight,
+        throttle: ThrottleTotals {
+            io_service_bytes_total: blkio.throttle.io_service_bytes_total,
+            io_service_bytes_recursive_total: blkio.throttle.io_service_bytes_recursive_total,
+            io_serviced_total: blkio.throttle.io_serviced_total,
+            io_serviced_recursive_total: blkio.throttle.io_serviced_recursive_total,
+        },
+    });
+
+    let mut devices = Devices::default();
+    devices.io_ops(&blkio.io_merged, |d| &mut d.io_merged);
+    devices.io_ops(&blkio.io_merged_recursive, |d| &mut d.io_merged_recursive);
+    devices.io_ops(&blkio.io_queued, |d| &mut d.io_queued);
+    devices.io_ops(&blkio.io_queued_recursive, |d| &mut d.io_queued_recursive);
+    devices.io_ops(&blkio.io_service_bytes, |d| &mut d.io_service_bytes);
+    devices.io_ops(&blkio.io_service_bytes_recursive, |d| {
+        &mut d.io_service_bytes_recursive
+    });
+    devices.io_ops(&blkio.io_serviced, |d| &mut d.io_serviced);
+    devices.io_ops(&blkio.io_serviced_recursive, |d| {
+        &mut d.io_serviced_recursive
+    });
+    devices.io_ops(&blkio.io_service_time, |d| &mut d.io_service_time);
+    devices.io_ops(&blkio.io_service_time_recursive, |d| {
+        &mut d.io_service_time_recursive
+    });
+    devices.io_ops(&blkio.io_wait_time, |d| &mut d.io_wait_time);
+    devices.io_ops(&blkio.io_wait_time_recursive, |d| {
+        &mut d.io_wait_time_recursive
+    });
+    devices.data(&blkio.leaf_weight_device, |d| &mut d.leaf_weight_device);
+    devices.data(&blkio.sectors, |d| &mut d.sectors);
+    devices.data(&blkio.sectors_recursive, |d| &mut d.sectors_recursive);
+    devices.data(&blkio.time, |d| &mut d.time);
+    devices.data(&blkio.time_recursive, |d| &mut d.time_recursive);
+    devices.data(&blkio.weight_device, |d| &mut d.weight_device);
+
+    let throttle = &blkio.throttle;
+    devices.io_ops(&throttle.io_service_bytes, |d| {
+        &mut throttle_of(d).io_service_bytes
+    });
+    devices.io_ops(&throttle.io_service_bytes_recursive, |d| {
+        &mut throttle_of(d).io_service_bytes_recursive
+    });
+    devices.io_ops(&throttle.io_serviced, |d| &mut throttle_of(d).io_serviced);
+    devices.io_ops(&throttle.io_serviced_recursive, |d| {
+        &mut throttle_of(d).io_serviced_recursive
+    });
+    devices.data(&throttle.read_bps_device, |d| {
+        &mut throttle_of(d).read_bps_device
+    });
+    devices.data(&throttle.read_iops_device, |d| {
+        &mut throttle_of(d).read_iops_device
+    });
+    devices.data(&throttle.write_bps_device, |d| {
+        &mut throttle_of(d).write_bps_device
+    });
+    devices.data(&throttle.write_iops_device, |d| {
+        &mut throttle_of(d).write_iops_device
+    });
+
+    devices.io_stat(&blkio.io_stat);
+
+    (totals, devices.into_series())
+}
+
+fn throttle_of(device: &mut BlkIoDevice) -> &mut ThrottleDevice {
+    device.throttle.get_or_insert_with(ThrottleDevice::default)
+}
+
+/// The per-device metrics, keyed and sorted by the device numbers.
+#[derive(Default)]
+struct Devices(BTreeMap<DeviceNumbers, BlkIoDevice>);
+
+impl Devices {
+    fn entry(&mut self, major: i16, minor: i16) -> &mut BlkIoDevice {
+        self.0.entry((major, minor)).or_default()
+    }
+
+    fn io_ops(
+        &mut self,
+        entries: &[IoService],
+        field: impl Fn(&mut BlkIoDevice) -> &mut Option<IoOps>,
+    ) {
+        for entry in entries {
+            *field(self.entry(entry.major, entry.minor)) = Some(IoOps {
+                read: entry.read,
+                write: entry.write,
+                sync: entry.sync,
+                r#async: entry.r#async,
+                discard: entry.discard,
+            });
+        }
+    }
+
+    fn data(
+        &mut self,
+        entries: &[BlkIoData],
+        field: impl Fn(&mut BlkIoDevice) -> &mut Option<u64>,
+    ) {
+        for entry in entries {
+            *field(self.entry(entry.major, entry.minor)) = Some(entry.data);
+        }
+    }
+
+    fn io_stat(&mut self, entries: &[IoStat]) {
+        for entry in entries {
+            self.entry(entry.major, entry.minor).io_stat = Some(IoStatOps {
+                rbytes: entry.rbytes,
+                wbytes: entry.wbytes,
+                rios: entry.rios,
+                wios: entry.wios,
+                dbytes: entry.dbytes,
+                dios: entry.dios,
+            });
+        }
+    }
+
+    fn into_series(self) -> Vec<LabeledSeries<CgroupSeries>> {
+        self.0
+            .into_iter()
+            .map(|(numbers, device)| {
+                let device_name = device_name(numbers).unwrap_or_default();
+                LabeledSeries {
+                    labels: vec![
+                        (MAJOR_LABEL, numbers.0.to_string()),
+                        (MINOR_LABEL, numbers.1.to_string()),
+                        (DEVICE_LABEL, device_name),
+                    ],
+                    metrics: CgroupSeries::BlkIo {
+                        blkio: Box::new(device),
+                    },
+                }
+            })
+            .collect()
+    }
+}
+
+/// Resolves the kernel name of a block device, e.g. `nvme0n1`, from its device numbers. The
+/// name is read on every scrape and not cached, since the numbers of a removed device can be
+/// given to another one.
+fn device_name((major, minor): DeviceNumbers) -> Option<String> {
+    let link =
+        std::fs::read_link(Path::new(SYS_DEV_BLOCK).join(format!("{major}:{minor}"))).ok()?;
+    Some(link.file_name()?.to_string_lossy().into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use cgroups_rs::blkio::BlkIoThrottle;
+
+    use super::*;
+
+    #[test]
+    fn blkio_entries_are_grouped_by_device() {
+        let blkio = BlkIo {
+            io_serviced_total: 12,
+            io_serviced: vec![
+                IoService {
+                    major: 8,
+                    minor: 0,
+                    read: 5,
+                    write: 7,
+                    total: 12,
+                    ..Default::default()
+                },
+                IoService {
+                    major: 259,
+                    minor: 1,
+                    read: 1,
+                    ..Default::default()
+                },
+            ],
+            sectors: vec![BlkIoData {
+                major: 8,
+                minor: 0,
+                data: 42,
+            }],
+            throttle: BlkIoThrottle {
+                read_bps_device: vec![BlkIoData {
+                    major: 8,
+                    minor: 0,
+                    data: 1024,
+                }],
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let (totals, series) = blkio_metrics(&blkio, false);
+        assert_eq!(totals.map(|totals| totals.io_serviced_total), Some(12));
+        assert_eq!(series.len(), 2);
+
+        // The devices are sorted by their numbers, not by their string representation.
+        let LabeledSeries { labels, metrics } = &series[0];
+        assert_eq!(labels[0], (MAJOR_LABEL, "8".to_string()));
+        assert_eq!(labels[1], (MINOR_LABEL, "0".to_string()));
+        let CgroupSeries::BlkIo { blkio: device } = metrics else {
+            panic!("expected a blkio series");
+        };
+        assert_eq!(device.io_serviced.as_ref().map(|ops| ops.write), Some(7));
+        assert_eq!(device.sectors, Some(42));
+        assert_eq!(
+            device.throttle.as_ref().and_then(|t| t.read_bps_device),
+            Some(1024)
+        );
+
+        let LabeledSeries { labels, metrics } = &series[1];
+        assert_eq!(labels[0], (MAJOR_LABEL, "259".to_string()));
+        assert_eq!(labels[1], (MINOR_LABEL, "1".to_string()));
+        let CgroupSeries::BlkIo { blkio: device } = metrics else {
+            panic!("expected a blkio series");
+        };
+        assert_eq!(device.io_serviced.as_ref().map(|ops| ops.read), Some(1));
+        assert!(device.sectors.is_none());
+    }
+
+    #[test]
+    fn io_stat_has_no_totals_on_v2() {
+        let blkio = BlkIo {
+            io_stat: vec![IoStat {
+                major: 259,
+                minor: 0,
+                rbytes: 4096,
+                wios: 3,
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        let (totals, series) = blkio_metrics(&blkio, true);
+        assert!(totals.is_none());
+        assert_eq!(series.len(), 1);
+        let CgroupSeries::BlkIo { blkio: device } = &series[0].metrics else {
+            panic!("expected a blkio series");
+        };
+        let io_stat = device.io_stat.as_ref().unwrap();
+        assert_eq!((io_stat.rbytes, io_stat.wios), (4096, 3));
+    }
+}
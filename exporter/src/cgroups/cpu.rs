@@ -0,0 +1,224 @@
+use std::path::Path;
+
+use cgroups_rs::{Controller as _, cpu::CpuController};
+use serde::Serialize;
+
+use crate::{
+    cgroups::fs::{flat_keyed, read_file, read_u64},
+    units::usec_to_seconds,
+};
+
+/// CPU controller metrics. The v1 `cpu.stat` is normalised into the v2 fields, see
+/// `docs/metrics.md` for the fields available on each version.
+#[derive(Serialize, Default, Debug, PartialEq)]
+pub struct CpuStat {
+    pub usage_usec: Option<u64>,
+    pub user_usec: Option<u64>,
+    pub system_usec: Option<u64>,
+    pub nice_usec: Option<u64>,
+    pub nr_periods: Option<u64>,
+    pub nr_throttled: Option<u64>,
+    pub throttled_usec: Option<u64>,
+    pub nr_bursts: Option<u64>,
+    pub burst_usec: Option<u64>,
+    /// The counters above in seconds, which replace them when `usecAsSeconds` is enabled.
+    pub usage_seconds: Option<f64>,
+    pub user_seconds: Option<f64>,
+    pub system_seconds: Option<f64>,
+    pub nice_seconds: Option<f64>,
+    pub throttled_seconds: Option<f64>,
+    pub burst_seconds: Option<f64>,
+
+    // Limits and weights.
+    /// v2: the quota of `cpu.max`, v1: `cpu.cfs_quota_us`. Left out when there is no limit.
+    pub quota_usec: Option<u64>,
+    /// v2: the period of `cpu.max`, v1: `cpu.cfs_period_us`.
+    pub period_usec: Option<u64>,
+    /// The number of CPUs the cgroup is allowed to use, derived from the quota and the period.
+    pub limit_cores: Option<f64>,
+    /// v2 only: `cpu.weight`.
+    pub weight: Option<u64>,
+    /// v2 only: `cpu.weight.nice`.
+    pub weight_nice: Option<i64>,
+    /// v1 only: `cpu.shares`.
+    pub shares: Option<u64>,
+}
+
+impl CpuStat {
+    pub fn from_controller(ctrl: &CpuController, v2: bool) -> Self {
+        let mut stat = if v2 {
+            let mut stat = parse_v2_stat(&ctrl.cpu().stat);
+            stat.set_v2_limits(ctrl.path());
+            stat
+        } else {
+            let mut stat = parse_v1_stat(&ctrl.cpu().stat);
+            stat.set_v1_limits(ctrl.path());
+            stat
+        };
+        stat.limit_cores = stat
+            .quota_usec
+            .zip(stat.period_usec)
+            .filter(|(_, period)| *period > 0)
+            .map(|(quota, period)| quota_to_cores(quota, period));
+        stat
+    }
+
+    /// Replaces the `*_usec` counters with `*_seconds` counters. The quota and the period are
+    /// left in microseconds, like in `cpu.max`.
+    pub fn usec_as_seconds(&mut self) {
+        self.usage_seconds = self.usage_usec.take().map(usec_to_seconds);
+        self.user_seconds = self.user_usec.take().map(usec_to_seconds);
+        self.system_seconds = self.system_usec.take().map(usec_to_seconds);
+        self.nice_seconds = self.nice_usec.take().map(usec_to_seconds);
+        self.throttled_seconds = self.throttled_usec.take().map(usec_to_seconds);
+        self.burst_seconds = self.burst_usec.take().map(usec_to_seconds);
+    }
+
+    fn set_v2_limits(&mut self, dir: &Path) {
+        if let Some(content) = read_file(dir, "cpu.max") {
+            (self.quota_usec, self.period_usec) = parse_cpu_max(&content);
+        }
+        self.weight = read_u64(dir, "cpu.weight");
+        self.weight_nice = read_file(dir, "cpu.weight.nice").and_then(|s| s.trim().parse().ok());
+    }
+
+    fn set_v1_limits(&mut self, dir: &Path) {
+        // The quota is -1 when there is no limit, which fails to parse as u64.
+        self.quota_usec = read_u64(dir, "cpu.cfs_quota_us");
+        self.period_usec = read_u64(dir, "cpu.cfs_period_us");
+        self.shares = read_u64(dir, "cpu.shares");
+    }
+}
+
+/// Parses `cpu.max`, which has the format `$MAX $PERIOD`. `$MAX` is `max` when there is no limit.
+fn parse_cpu_max(content: &str) -> (Option<u64>, Option<u64>) {
+    let mut parts = content.split_whitespace();
+    let quota = parts.next().and_then(|quota| quota.parse().ok());
+    let period = parts.next().and_then(|period| period.parse().ok());
+    (quota, period)
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn quota_to_cores(quota: u64, period: u64) -> f64 {
+    quota as f64 / period as f64
+}
+
+fn parse_v2_stat(stat: &str) -> CpuStat {
+    let mut v2_stat = CpuStat::default();
+    for (key, value) in flat_keyed(stat) {
+        let value = Some(value);
+        match key {
+            "usage_usec" => v2_stat.usage_usec = value,
+            "user_usec" => v2_stat.user_usec = value,
+            "system_usec" => v2_stat.system_usec = value,
+            "nice_usec" => v2_stat.nice_usec = value,
+            "nr_periods" => v2_stat.nr_periods = value,
+            "nr_throttled" => v2_stat.nr_throttled = value,
+            "throttled_usec" => v2_stat.throttled_usec = value,
+            "nr_bursts" => v2_stat.nr_bursts = value,
+            "burst_usec" => v2_stat.burst_usec = value,
+            _ => {}
+        }
+    }
+    v2_stat
+}
+
+/// Parses the v1 `cpu.stat`, which only has the CFS bandwidth statistics.
+/// The times are reported in nanoseconds, and converted to microseconds to match v2.
+fn parse_v1_stat(stat: &str) -> CpuStat {
+    let mut v1_stat = CpuStat::default();
+    for (key, value) in flat_keyed(stat) {
+        match key {
+            "nr_periods" => v1_stat.nr_periods = Some(value),
+            "nr_throttled" => v1_stat.nr_throttled = Some(value),
+            "throttled_time" => v1_stat.throttled_usec = Some(value / 1000),
+            "nr_bursts" => v1_stat.nr_bursts = Some(value),
+            "burst_time" => v1_stat.burst_usec = Some(value / 1000),
+            _ => {}
+        }
+    }
+    v1_stat
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::{
+        cgroups::metrics::CgroupMetrics,
+        metadata::METADATA,
+        tests::{assert_sample, sample_value},
+    };
+
+    use super::*;
+
+    fn export(stat: CpuStat) -> String {
+        let metrics = CgroupMetrics {
+            cpu: Some(stat),
+            ..Default::default()
+        };
+        #[allow(clippy::explicit_auto_deref)]
+        serde_prom::to_prometheus_text(&metrics, Some("cgroup"), &*METADATA, HashMap::new())
+            .unwrap()
+    }
+
+    #[test]
+    fn parse_cpu_max_with_limit() {
+        assert_eq!(
+            parse_cpu_max("150000 100000\n"),
+            (Some(150_000), Some(100_000))
+        );
+        assert!((quota_to_cores(150_000, 100_000) - 1.5).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn parse_v1_cpu_stat() {
+        let content = "nr_periods 120\nnr_throttled 15\nthrottled_time 2500000000\n";
+        assert_eq!(
+            parse_v1_stat(content),
+            CpuStat {
+                nr_periods: Some(120),
+                nr_throttled: Some(15),
+                throttled_usec: Some(2_500_000),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn parse_cpu_max_without_limit() {
+        assert_eq!(parse_cpu_max("max 100000\n"), (None, Some(100_000)));
+    }
+
+    #[test]
+    fn usec_as_seconds_exports_seconds() {
+        let content = "usage_usec 2500000\nuser_usec 2000000\nsystem_usec 500000\n\
+                       nr_throttled 3\nthrottled_usec 250000\n";
+        let text = export(parse_v2_stat(content));
+        assert_sample(&text, "cgroup_cpu_usage_usec_total", 2_500_000.0);
+        assert_sample(&text, "cgroup_cpu_throttled_usec_total", 250_000.0);
+
+        let mut stat = parse_v2_stat(content);
+        stat.usec_as_seconds();
+        let text = export(stat);
+        assert_sample(&text, "cgroup_cpu_usage_seconds_total", 2.5);
+        assert_sample(&text, "cgroup_cpu_user_seconds_total", 2.0);
+        assert_sample(&text, "cgroup_cpu_system_seconds_total", 0.5);
+        assert_sample(&text, "cgroup_cpu_throttled_seconds_total", 0.25);
+        assert_eq!(sample_value(&text, "cgroup_cpu_usage_usec_total"), None);
+        assert_eq!(sample_value(&text, "cgroup_cpu_throttled_usec_total"), None);
+    }
+
+    #[test]
+    fn v1_throttled_time_is_exported_in_usec_and_seconds() {
+        // `throttled_time` is in nanoseconds.
+        let content = "nr_periods 120\nnr_throttled 15\nthrottled_time 2500000000\n";
+        let text = export(parse_v1_stat(content));
+        assert_sample(&text, "cgroup_cpu_throttled_usec_total", 2_500_000.0);
+
+        let mut stat = parse_v1_stat(content);
+        stat.usec_as_seconds();
+        let text = export(stat);
+        assert_sample(&text, "cgroup_cpu_throttled_seconds_total", 2.5);
+    }
+}
@@ -0,0 +1,116 @@
+use cgroups_rs::cpuacct::CpuAcct;
+use serde::Serialize;
+
+use crate::{cgroups::metrics::CgroupSeries, render::LabeledSeries, units::nanos_to_seconds};
+
+const CPU_LABEL: &str = "cpu";
+const MODE_LABEL: &str = "mode";
+
+/// The CPU accounting metrics of a cgroup v1, converted from nanoseconds to seconds. The per-CPU
+/// usage vectors are emitted as separate series instead, see [`per_cpu_series`].
+#[derive(Serialize, Default)]
+pub struct CpuAcctStat {
+    /// Left out when the usage is broken down per CPU, which uses the same metric name.
+    pub usage_seconds: Option<f64>,
+    pub usage_user_seconds: f64,
+    pub usage_sys_seconds: f64,
+}
+
+/// The usage of a single CPU in a single mode, exported as `cpuacct_usage_seconds_total` like the
+/// total usage.
+#[derive(Serialize)]
+pub struct CpuAcctUsage {
+    pub usage_seconds: f64,
+}
+
+impl From<&CpuAcct> for CpuAcctStat {
+    fn from(cpuacct: &CpuAcct) -> Self {
+        CpuAcctStat {
+            usage_seconds: Some(nanos_to_seconds(cpuacct.usage)),
+            usage_user_seconds: nanos_to_seconds(cpuacct.usage_user),
+            usage_sys_seconds: nanos_to_seconds(cpuacct.usage_sys),
+        }
+    }
+}
+
+/// Returns one series per CPU and mode from `cpuacct.usage_percpu_user` and
+/// `cpuacct.usage_percpu_sys`, labeled with the CPU number and `user` or `system`.
+pub fn per_cpu_series(cpuacct: &CpuAcct) -> Vec<LabeledSeries<CgroupSeries>> {
+    let user = parse_per_cpu(&cpuacct.usage_percpu_user).map(|usage| ("user", usage));
+    let system = parse_per_cpu(&cpuacct.usage_percpu_sys).map(|usage| ("system", usage));
+    user.chain(system)
+        .map(|(mode, (cpu, nanos))| LabeledSeries {
+            labels: vec![(CPU_LABEL, cpu.to_string()), (MODE_LABEL, mode.to_string())],
+            metrics: CgroupSeries::CpuAcct {
+                cpuacct: CpuAcctUsage {
+                    usage_seconds: nanos_to_seconds(nanos),
+                },
+            },
+        })
+        .collect()
+}
+
+/// Parses a per-CPU file, which has the usage of each CPU in nanoseconds separated by spaces.
+fn parse_per_cpu(content: &str) -> impl Iterator<Item = (usize, u64)> + '_ {
+    content
+        .split_whitespace()
+        .enumerate()
+        .filter_map(|(cpu, usage)| Some((cpu, usage.parse().ok()?)))
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn per_cpu_series_by_mode() {
+        let cpuacct = CpuAcct {
+            usage_percpu_user: "1500000000 0 \n".to_string(),
+            usage_percpu_sys: "250000000 1000000000 \n".to_string(),
+            ..Default::default()
+        };
+        let series = per_cpu_series(&cpuacct);
+        let labels: Vec<_> = series.iter().map(|series| series.labels.clone()).collect();
+        assert_eq!(
+            labels,
+            vec![
+                vec![
+                    (CPU_LABEL, "0".to_string()),
+                    (MODE_LABEL, "user".to_string())
+                ],
+                vec![
+                    (CPU_LABEL, "1".to_string()),
+                    (MODE_LABEL, "user".to_string())
+                ],
+                vec![
+                    (CPU_LABEL, "0".to_string()),
+                    (MODE_LABEL, "system".to_string())
+                ],
+                vec![
+                    (CPU_LABEL, "1".to_string()),
+                    (MODE_LABEL, "system".to_string())
+                ],
+            ]
+        );
+        let CgroupSeries::CpuAcct { cpuacct } = &series[0].metrics else {
+            panic!("expected a cpuacct series");
+        };
+        assert!((cpuacct.usage_seconds - 1.5).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn usage_in_seconds() {
+        let cpuacct = CpuAcct {
+            usage: 2_500_000_000,
+            usage_user: 2_000_000_000,
+            usage_sys: 500_000_000,
+            ..Default::default()
+        };
+        let stat = CpuAcctStat::from(&cpuacct);
+        assert!((stat.usage_seconds.unwrap() - 2.5).abs() < f64::EPSILON);
+        assert!((stat.usage_user_seconds - 2.0).abs() < f64::EPSILON);
+        assert!((stat.usage_sys_seconds - 0.5).abs() < f64::EPSILON);
+    }
+}
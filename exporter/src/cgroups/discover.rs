@@ -2,6 +2,7 @@ use std::sync::{Arc, LazyLock};
 
 use anyhow::Context as _;
 use cgroups_explorer::Explorer;
+use cgroups_exporter_config::MetricsConfig;
 use cgroups_rs::Cgroup;
 use tokio::sync::{Semaphore, mpsc};
 use tokio_stream::{Stream, StreamExt, wrappers::ReceiverStream};
@@ -9,6 +10,7 @@ use tracing::{debug, error};
 
 use crate::{
     cgroups::metrics::CgroupMetrics,
+    filter::MetricFilter,
     matcher::{CgroupMatcher, MatchableCgroupConfig, NameMatcher},
     render::MatchGroup,
     shell::ShellEvaluator,
@@ -62,13 +64,18 @@ async fn discover_cgroup_metrics(
         return;
     };
     let _join_res = tokio::task::spawn_blocking(move || {
-        let series_result = discover_cgroup_metrics_blocking(&config.match_by, &evaluator);
+        let series_result = discover_cgroup_metrics_blocking(
+            &config.match_by,
+            &config.metrics,
+            &config.rules.filter,
+            &evaluator,
+        );
         let _ = sender.blocking_send(series_result.map(|cgroups| {
             let mut metrics_config = config.metrics;
             if metrics_config.namespace.is_none() {
                 metrics_config.namespace = Some(NAMESPACE.to_string());
             }
-            MatchGroup::new(cgroups, metrics_config)
+            MatchGroup::new(cgroups, metrics_config, config.rules)
         }));
     })
     .await;
@@ -76,6 +83,8 @@ async fn discover_cgroup_metrics(
 
 fn discover_cgroup_metrics_blocking(
     matcher: &CgroupMatcher,
+    metrics_config: &MetricsConfig,
+    filter: &MetricFilter,
     evaluator: &ShellEvaluator,
 ) -> anyhow::Result<Vec<CgroupMetrics>> {
     let cgroups_iter = discover_cgroup_for_match_blocking(matcher)
@@ -87,7 +96,13 @@ fn discover_cgroup_metrics_blocking(
 
     let metrics = cgroups_iter
         .filter_map(|cgroup| {
-            match CgroupMetrics::from_cgroup_blocking(&cgroup, matcher, evaluator) {
+            match CgroupMetrics::from_cgroup_blocking(
+                &cgroup,
+                matcher,
+                metrics_config,
+                filter,
+                evaluator,
+            ) {
                 Ok(answer) => Some(answer),
                 Err(err) => {
                     // Logging at the debug level to avoid cluttering the logs in case of many cgroups
@@ -0,0 +1,63 @@
+//! Helpers for reading cgroup interface files that cgroups-rs doesn't parse for us.
+use std::path::{Path, PathBuf};
+
+use cgroups_rs::{Cgroup, Controller as _};
+
+/// Returns the directory of a cgroup in the v2 unified hierarchy. All the controllers share it,
+/// so it is the path of any of them, which is built from the root of the hierarchy the cgroup was
+/// loaded from. `None` when the cgroup has no controllers.
+pub fn unified_dir(cgroup: &Cgroup) -> Option<PathBuf> {
+    cgroup
+        .subsystems()
+        .first()
+        .map(|subsystem| subsystem.to_controller().path().to_path_buf())
+}
+
+/// Reads a cgroup interface file. Returns `None` if the file doesn't exist or can't be read.
+pub fn read_file(dir: &Path, name: &str) -> Option<String> {
+    std::fs::read_to_string(dir.join(name)).ok()
+}
+
+/// Reads a cgroup interface file that contains a single number.
+pub fn read_u64(dir: &Path, name: &str) -> Option<u64> {
+    read_file(dir, name)?.trim().parse().ok()
+}
+
+/// The value reported for limits that are set to `max`, i.e. when there is no limit.
+pub const UNLIMITED: u64 = u64::MAX;
+
+/// Reads a limit such as `memory.max`, which is either a number or `max`.
+/// `max` is reported as [`UNLIMITED`].
+pub fn read_limit(dir: &Path, name: &str) -> Option<u64> {
+    parse_limit(&read_file(dir, name)?)
+}
+
+fn parse_limit(content: &str) -> Option<u64> {
+    match content.trim() {
+        "max" => Some(UNLIMITED),
+        value => value.parse().ok(),
+    }
+}
+
+/// Iterates over the `key value` pairs of a flat keyed file such as `memory.stat`.
+/// Lines that don't have a numeric value are skipped.
+pub fn flat_keyed(content: &str) -> impl Iterator<Item = (&str, u64)> {
+    content.lines().filter_map(|line| {
+        let mut parts = line.split_whitespace();
+        let key = parts.next()?;
+        let value = parts.next()?.parse().ok()?;
+        Some((key, value))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_limit_max_is_unlimited() {
+        assert_eq!(parse_limit("max\n"), Some(UNLIMITED));
+        assert_eq!(parse_limit("536870912\n"), Some(536_870_912));
+        assert_eq!(parse_limit(""), None);
+    }
+}
@@ -0,0 +1,125 @@
+use std::path::Path;
+
+use cgroups_rs::{Controller as _, hugetlb::HugeTlbController};
+use serde::Serialize;
+
+use crate::{
+    cgroups::{
+        fs::{flat_keyed, read_file, read_u64},
+        metrics::CgroupSeries,
+    },
+    render::LabeledSeries,
+};
+
+const PAGE_SIZE_LABEL: &str = "pagesize";
+
+/// Metrics of the hugetlb controller for a single huge page size.
+/// The v1 and v2 interface files are mapped onto the same fields.
+#[derive(Serialize, Default, Debug, PartialEq)]
+pub struct HugeTlbStat {
+    /// v1: `usage_in_bytes`, v2: `current`.
+    pub usage_bytes: Option<u64>,
+    /// v1: `limit_in_bytes`, v2: `max`. Left out when there is no limit on v2.
+    pub limit_bytes: Option<u64>,
+    /// v1 only: `max_usage_in_bytes`.
+    pub max_usage_bytes: Option<u64>,
+    /// v1: `failcnt`, v2: the `max` entry of `events`.
+    pub failcnt: Option<u64>,
+}
+
+/// Returns one series for each huge page size supported by the system, labeled by the page size.
+pub fn hugetlb_series(ctrl: &HugeTlbController, v2: bool) -> Vec<LabeledSeries<CgroupSeries>> {
+    ctrl.get_sizes()
+        .into_iter()
+        .map(|size| {
+            let stat = if v2 {
+                HugeTlbStat::from_v2_dir(ctrl.path(), &size)
+            } else {
+                HugeTlbStat::from_v1_dir(ctrl.path(), &size)
+            };
+            LabeledSeries {
+                labels: vec![(PAGE_SIZE_LABEL, size)],
+                metrics: CgroupSeries::HugeTlb { hugetlb: stat },
+            }
+        })
+        .collect()
+}
+
+impl HugeTlbStat {
+    fn from_v1_dir(dir: &Path, size: &str) -> Self {
+        HugeTlbStat {
+            usage_bytes: read_u64(dir, &format!("hugetlb.{size}.usage_in_bytes")),
+            limit_bytes: read_u64(dir, &format!("hugetlb.{size}.limit_in_bytes")),
+            max_usage_bytes: read_u64(dir, &format!("hugetlb.{size}.max_usage_in_bytes")),
+            failcnt: read_u64(dir, &format!("hugetlb.{size}.failcnt")),
+        }
+    }
+
+    fn from_v2_dir(dir: &Path, size: &str) -> Self {
+        HugeTlbStat {
+            usage_bytes: read_u64(dir, &format!("hugetlb.{size}.current")),
+            // `max` doesn't parse as a number, which leaves the limit out.
+            limit_bytes: read_u64(dir, &format!("hugetlb.{size}.max")),
+            max_usage_bytes: None,
+            failcnt: read_file(dir, &format!("hugetlb.{size}.events"))
+                .and_then(|content| parse_events_max(&content)),
+        }
+    }
+}
+
+/// Returns the `max` entry of a v2 `hugetlb.<size>.events` file, the number of allocations
+/// that failed because of the limit.
+fn parse_events_max(content: &str) -> Option<u64> {
+    flat_keyed(content).find_map(|(key, value)| (key == "max").then_some(value))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn parse_v2_events() {
+        assert_eq!(parse_events_max("max 5\n"), Some(5));
+        assert_eq!(parse_events_max(""), None);
+    }
+
+    #[test]
+    fn v1_and_v2_files_map_to_the_same_fields() {
+        let dir = std::env::temp_dir().join(format!("hugetlb-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("hugetlb.2MB.current"), "4194304\n").unwrap();
+        fs::write(dir.join("hugetlb.2MB.max"), "max\n").unwrap();
+        fs::write(dir.join("hugetlb.2MB.events"), "max 2\n").unwrap();
+        fs::write(dir.join("hugetlb.1GB.usage_in_bytes"), "0\n").unwrap();
+        fs::write(dir.join("hugetlb.1GB.limit_in_bytes"), "1073741824\n").unwrap();
+        fs::write(dir.join("hugetlb.1GB.max_usage_in_bytes"), "1073741824\n").unwrap();
+        fs::write(dir.join("hugetlb.1GB.failcnt"), "1\n").unwrap();
+
+        let v2 = HugeTlbStat::from_v2_dir(&dir, "2MB");
+        let v1 = HugeTlbStat::from_v1_dir(&dir, "1GB");
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(
+            v2,
+            HugeTlbStat {
+                usage_bytes: Some(4_194_304),
+                limit_bytes: None,
+                max_usage_bytes: None,
+                failcnt: Some(2),
+            }
+        );
+        assert_eq!(
+            v1,
+            HugeTlbStat {
+                usage_bytes: Some(0),
+                limit_bytes: Some(1_073_741_824),
+                max_usage_bytes: Some(1_073_741_824),
+                failcnt: Some(1),
+            }
+        );
+    }
+}
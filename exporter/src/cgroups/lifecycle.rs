@@ -0,0 +1,109 @@
+use std::path::Path;
+
+use serde::Serialize;
+
+use crate::cgroups::fs::{flat_keyed, read_file, read_u64};
+
+/// Metrics of the core cgroup v2 interface files, which exist in every cgroup regardless of the
+/// enabled controllers.
+#[derive(Serialize, Default, Debug, PartialEq)]
+pub struct CoreStat {
+    /// From `cgroup.stat`.
+    pub nr_descendants: Option<u64>,
+    /// From `cgroup.stat`. Descendants that were removed but are still pinned by some resource.
+    pub nr_dying_descendants: Option<u64>,
+    /// From `cgroup.events`. 1 when the cgroup or its descendants have live processes.
+    pub populated: Option<u64>,
+    /// From `cgroup.events`. 1 when the cgroup is frozen.
+    pub frozen: Option<u64>,
+    /// From `cgroup.freeze`. 1 when the cgroup was requested to be frozen. Not present on the root.
+    pub freeze: Option<u64>,
+}
+
+impl CoreStat {
+    /// Reads the core interface files from a cgroup v2 directory, see `fs::unified_dir` for where
+    /// it is mounted.
+    pub fn from_dir(dir: &Path) -> Self {
+        let mut stat = CoreStat {
+            freeze: read_u64(dir, "cgroup.freeze"),
+            ..Default::default()
+        };
+        if let Some(content) = read_file(dir, "cgroup.stat") {
+            stat.set_stat(&content);
+        }
+        if let Some(content) = read_file(dir, "cgroup.events") {
+            stat.set_events(&content);
+        }
+        stat
+    }
+
+    fn set_stat(&mut self, content: &str) {
+        for (key, value) in flat_keyed(content) {
+            match key {
+                "nr_descendants" => self.nr_descendants = Some(value),
+                "nr_dying_descendants" => self.nr_dying_descendants = Some(value),
+                _ => {}
+            }
+        }
+    }
+
+    fn set_events(&mut self, content: &str) {
+        for (key, value) in flat_keyed(content) {
+            match key {
+                "populated" => self.populated = Some(value),
+                "frozen" => self.frozen = Some(value),
+                _ => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn parse_cgroup_stat_and_events() {
+        let mut stat = CoreStat::default();
+        stat.set_stat("nr_descendants 4\nnr_dying_descendants 17\nnr_subsys_memory 5\n");
+        stat.set_events("populated 1\nfrozen 0\n");
+        assert_eq!(
+            stat,
+            CoreStat {
+                nr_descendants: Some(4),
+                nr_dying_descendants: Some(17),
+                populated: Some(1),
+                frozen: Some(0),
+                freeze: None,
+            }
+        );
+    }
+
+    #[test]
+    fn read_from_cgroup_dir() {
+        let dir =
+            std::env::temp_dir().join(format!("cgroups_exporter_core_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("cgroup.stat"),
+            "nr_descendants 2\nnr_dying_descendants 0\n",
+        )
+        .unwrap();
+        std::fs::write(dir.join("cgroup.events"), "populated 1\nfrozen 0\n").unwrap();
+        let stat = CoreStat::from_dir(&dir);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(
+            stat,
+            CoreStat {
+                nr_descendants: Some(2),
+                nr_dying_descendants: Some(0),
+                populated: Some(1),
+                frozen: Some(0),
+                freeze: None,
+            }
+        );
+    }
+}
@@ -0,0 +1,303 @@
+use std::path::Path;
+
+use cgroups_rs::memory::Memory;
+use serde::Serialize;
+
+use crate::cgroups::fs::{flat_keyed, read_file, read_limit, read_u64};
+
+/// Memory controller metrics. cgroups-rs models the v1 interface files, so v2 cgroups are read
+/// natively instead.
+#[derive(Serialize)]
+#[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
+pub enum MemoryMetrics {
+    V1(Memory),
+    V2(MemoryV2),
+}
+
+/// Memory metrics derived from the memory controller the same way as cAdvisor, so that they
+/// match its `container_memory_*` metrics on both cgroup versions.
+#[derive(Default, Debug, PartialEq)]
+pub struct DerivedMemory {
+    /// The usage minus the inactive file cache, which is what the OOM killer looks at.
+    pub working_set_bytes: u64,
+    /// v1: `total_rss`, v2: `anon`.
+    pub rss: u64,
+    /// v1: `total_cache`, v2: `file`.
+    pub cache: u64,
+    /// v1: `total_mapped_file`, v2: `file_mapped`.
+    pub mapped_file: u64,
+}
+
+impl MemoryMetrics {
+    /// Computes the derived memory metrics.
+    pub fn derived(&self) -> DerivedMemory {
+        match self {
+            MemoryMetrics::V1(memory) => DerivedMemory {
+                working_set_bytes: memory
+                    .usage_in_bytes
+                    .saturating_sub(memory.stat.total_inactive_file),
+                rss: memory.stat.total_rss,
+                cache: memory.stat.total_cache,
+                mapped_file: memory.stat.total_mapped_file,
+            },
+            MemoryMetrics::V2(memory) => DerivedMemory {
+                working_set_bytes: memory
+                    .usage_in_bytes
+                    .unwrap_or_default()
+                    .saturating_sub(memory.stat.inactive_file.unwrap_or_default()),
+                rss: memory.stat.anon.unwrap_or_default(),
+                cache: memory.stat.file.unwrap_or_default(),
+                mapped_file: memory.stat.file_mapped.unwrap_or_default(),
+            },
+        }
+    }
+}
+
+/// Memory controller metrics of a cgroup v2.
+#[derive(Serialize, Default)]
+pub struct MemoryV2 {
+    /// From `memory.current`. Named after the v1 field so that both versions share a metric.
+    pub usage_in_bytes: Option<u64>,
+    /// From `memory.max`, shares the v1 metric. The limits are `u64::MAX` when set to `max`.
+    pub limit_in_bytes: Option<u64>,
+    /// From `memory.peak`, shares the v1 metric. Needs Linux 5.19 or newer.
+    pub max_usage_in_bytes: Option<u64>,
+    /// From `memory.high`, the throttling limit.
+    pub high_bytes: Option<u64>,
+    /// From `memory.low`, the best-effort protection.
+    pub low_bytes: Option<u64>,
+    /// From `memory.min`, the hard protection.
+    pub min_bytes: Option<u64>,
+    /// From `memory.swap.current`.
+    pub swap_usage_bytes: Option<u64>,
+    /// From `memory.swap.max`.
+    pub swap_limit_bytes: Option<u64>,
+    pub stat: MemoryStatV2,
+    pub events: MemoryEvents,
+}
+
+/// The contents of `memory.stat` of a cgroup v2. All the sizes are in bytes.
+#[derive(Serialize, Default, Debug, PartialEq)]
+pub struct MemoryStatV2 {
+    pub anon: Option<u64>,
+    pub file: Option<u64>,
+    pub kernel: Option<u64>,
+    pub kernel_stack: Option<u64>,
+    pub pagetables: Option<u64>,
+    pub percpu: Option<u64>,
+    pub sock: Option<u64>,
+    pub vmalloc: Option<u64>,
+    pub shmem: Option<u64>,
+    pub zswap: Option<u64>,
+    pub zswapped: Option<u64>,
+    pub file_mapped: Option<u64>,
+    pub file_dirty: Option<u64>,
+    pub file_writeback: Option<u64>,
+    pub swapcached: Option<u64>,
+    pub anon_thp: Option<u64>,
+    pub file_thp: Option<u64>,
+    pub shmem_thp: Option<u64>,
+    pub inactive_anon: Option<u64>,
+    pub active_anon: Option<u64>,
+    pub inactive_file: Option<u64>,
+    pub active_file: Option<u64>,
+    pub unevictable: Option<u64>,
+    pub slab_reclaimable: Option<u64>,
+    pub slab_unreclaimable: Option<u64>,
+    pub slab: Option<u64>,
+    pub workingset_refault_anon: Option<u64>,
+    pub workingset_refault_file: Option<u64>,
+    pub workingset_activate_anon: Option<u64>,
+    pub workingset_activate_file: Option<u64>,
+    pub workingset_restore_anon: Option<u64>,
+    pub workingset_restore_file: Option<u64>,
+    pub workingset_nodereclaim: Option<u64>,
+    pub pgfault: Option<u64>,
+    pub pgmajfault: Option<u64>,
+    pub pgrefill: Option<u64>,
+    pub pgscan: Option<u64>,
+    pub pgsteal: Option<u64>,
+    pub pgactivate: Option<u64>,
+    pub pgdeactivate: Option<u64>,
+    pub pglazyfree: Option<u64>,
+    pub pglazyfreed: Option<u64>,
+    pub thp_fault_alloc: Option<u64>,
+    pub thp_collapse_alloc: Option<u64>,
+}
+
+/// The contents of `memory.events` of a cgroup v2. All the fields are event counters.
+#[derive(Serialize, Default, Debug, PartialEq)]
+pub struct MemoryEvents {
+    pub low: Option<u64>,
+    pub high: Option<u64>,
+    pub max: Option<u64>,
+    pub oom: Option<u64>,
+    pub oom_kill: Option<u64>,
+    pub oom_group_kill: Option<u64>,
+}
+
+impl MemoryV2 {
+    /// Reads the memory controller files from a cgroup v2 directory.
+    pub fn from_dir(dir: &Path) -> Self {
+        MemoryV2 {
+            usage_in_bytes: read_u64(dir, "memory.current"),
+            limit_in_bytes: read_limit(dir, "memory.max"),
+            max_usage_in_bytes: read_u64(dir, "memory.peak"),
+            high_bytes: read_limit(dir, "memory.high"),
+            low_bytes: read_limit(dir, "memory.low"),
+            min_bytes: read_limit(dir, "memory.min"),
+            swap_usage_bytes: read_u64(dir, "memory.swap.current"),
+            swap_limit_bytes: read_limit(dir, "memory.swap.max"),
+            stat: read_file(dir, "memory.stat")
+                .map(|content| parse_memory_stat(&content))
+                .unwrap_or_default(),
+            events: read_file(dir, "memory.events")
+                .map(|content| parse_memory_events(&content))
+                .unwrap_or_default(),
+        }
+    }
+}
+
+fn parse_memory_stat(content: &str) -> MemoryStatV2 {
+    let mut stat = MemoryStatV2::default();
+    for (key, value) in flat_keyed(content) {
+        let value = Some(value);
+        match key {
+            "anon" => stat.anon = value,
+            "file" => stat.file = value,
+            "kernel" => stat.kernel = value,
+            "kernel_stack" => stat.kernel_stack = value,
+            "pagetables" => stat.pagetables = value,
+            "percpu" => stat.percpu = value,
+            "sock" => stat.sock = value,
+            "vmalloc" => stat.vmalloc = value,
+            "shmem" => stat.shmem = value,
+            "zswap" => stat.zswap = value,
+            "zswapped" => stat.zswapped = value,
+            "file_mapped" => stat.file_mapped = value,
+            "file_dirty" => stat.file_dirty = value,
+            "file_writeback" => stat.file_writeback = value,
+            "swapcached" => stat.swapcached = value,
+            "anon_thp" => stat.anon_thp = value,
+            "file_thp" => stat.file_thp = value,
+            "shmem_thp" => stat.shmem_thp = value,
+            "inactive_anon" => stat.inactive_anon = value,
+            "active_anon" => stat.active_anon = value,
+            "inactive_file" => stat.inactive_file = value,
+            "active_file" => stat.active_file = value,
+            "unevictable" => stat.unevictable = value,
+            "slab_reclaimable" => stat.slab_reclaimable = value,
+            "slab_unreclaimable" => stat.slab_unreclaimable = value,
+            "slab" => stat.slab = value,
+            "workingset_refault_anon" => stat.workingset_refault_anon = value,
+            "workingset_refault_file" => stat.workingset_refault_file = value,
+            "workingset_activate_anon" => stat.workingset_activate_anon = value,
+            "workingset_activate_file" => stat.workingset_activate_file = value,
+            "workingset_restore_anon" => stat.workingset_restore_anon = value,
+            "workingset_restore_file" => stat.workingset_restore_file = value,
+            "workingset_nodereclaim" => stat.workingset_nodereclaim = value,
+            "pgfault" => stat.pgfault = value,
+            "pgmajfault" => stat.pgmajfault = value,
+            "pgrefill" => stat.pgrefill = value,
+            "pgscan" => stat.pgscan = value,
+            "pgsteal" => stat.pgsteal = value,
+            "pgactivate" => stat.pgactivate = value,
+            "pgdeactivate" => stat.pgdeactivate = value,
+            "pglazyfree" => stat.pglazyfree = value,
+            "pglazyfreed" => stat.pglazyfreed = value,
+            "thp_fault_alloc" => stat.thp_fault_alloc = value,
+            "thp_collapse_alloc" => stat.thp_collapse_alloc = value,
+            _ => {}
+        }
+    }
+    stat
+}
+
+fn parse_memory_events(content: &str) -> MemoryEvents {
+    let mut events = MemoryEvents::default();
+    for (key, value) in flat_keyed(content) {
+        let value = Some(value);
+        match key {
+            "low" => events.low = value,
+            "high" => events.high = value,
+            "max" => events.max = value,
+            "oom" => events.oom = value,
+            "oom_kill" => events.oom_kill = value,
+            "oom_group_kill" => events.oom_group_kill = value,
+            _ => {}
+        }
+    }
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn parse_v2_memory_stat() {
+        let content = "anon 1048576\nfile 2097152\nkernel_stack 16384\nslab_reclaimable 4096\n\
+                       sock 0\nshmem 8192\nfile_dirty 12288\npgsteal 42\n\
+                       workingset_refault_anon 3\nworkingset_refault_file 7\nunknown_key 1\n";
+        let stat = parse_memory_stat(content);
+        assert_eq!(
+            stat,
+            MemoryStatV2 {
+                anon: Some(1_048_576),
+                file: Some(2_097_152),
+                kernel_stack: Some(16384),
+                slab_reclaimable: Some(4096),
+                sock: Some(0),
+                shmem: Some(8192),
+                file_dirty: Some(12288),
+                pgsteal: Some(42),
+                workingset_refault_anon: Some(3),
+                workingset_refault_file: Some(7),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn derived_v2_memory() {
+        let memory = MemoryMetrics::V2(MemoryV2 {
+            usage_in_bytes: Some(10_000),
+            stat: MemoryStatV2 {
+                anon: Some(6000),
+                file: Some(4000),
+                file_mapped: Some(1000),
+                inactive_file: Some(3000),
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+        assert_eq!(
+            memory.derived(),
+            DerivedMemory {
+                working_set_bytes: 7000,
+                rss: 6000,
+                cache: 4000,
+                mapped_file: 1000,
+            }
+        );
+    }
+
+    #[test]
+    fn parse_v2_memory_events() {
+        let content = "low 0\nhigh 12\nmax 3\noom 1\noom_kill 1\n";
+        assert_eq!(
+            parse_memory_events(content),
+            MemoryEvents {
+                low: Some(0),
+                high: Some(12),
+                max: Some(3),
+                oom: Some(1),
+                oom_kill: Some(1),
+                oom_group_kill: None,
+            }
+        );
+    }
+}
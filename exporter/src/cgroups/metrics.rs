@@ -2,52 +2,98 @@ use std::collections::HashMap;
 
 use anyhow::Context as _;
 use cgroups_rs::{
-    Cgroup,
-    blkio::{BlkIo, BlkIoController},
-    cpu::CpuController,
-    cpuacct::{CpuAcct, CpuAcctController},
-    cpuset::CpuSet,
-    memory::{MemController, Memory},
+    Cgroup, Controller as _, blkio::BlkIoController, cpu::CpuController,
+    cpuacct::CpuAcctController, cpuset::CpuSet, hugetlb::HugeTlbController, memory::MemController,
+    pid::PidController,
 };
 use new_string_template::template::Template;
 use procfs::process::Process;
+use regex::Regex;
 use saturating_cast::SaturatingCast as _;
 use serde::Serialize;
 
 use crate::{
+    cgroups::{
+        blkio::{BlkIoDevice, BlkIoTotals, blkio_metrics},
+        cpu::CpuStat,
+        cpuacct::{CpuAcctStat, CpuAcctUsage, per_cpu_series},
+        fs::unified_dir,
+        hugetlb::{HugeTlbStat, hugetlb_series},
+        lifecycle::CoreStat,
+        memory::{DerivedMemory, MemoryMetrics, MemoryV2},
+        network::{NetworkInterface, network_series},
+        pids::PidsStat,
+        pressure::Pressure,
+    },
+    filter::MetricFilter,
+    labels::render_labels,
     matcher::{CgroupMatcher, NameMatcher},
-    procs::{Proc, ProcessMetrics},
-    render::Named,
+    metadata::METADATA,
+    procs::{PROCESS_METRICS, Proc, ProcReads, ProcessMetrics, ProcessSeries},
+    render::{Labeled, LabeledSeries, Named},
     shell::Evaluator,
 };
 
-use cgroups_exporter_config::{RewriteCgroupName, Templated};
+use cgroups_exporter_config::{Controller, MetricsConfig, RewriteCgroupName, Templated};
 
 #[derive(Serialize, Default)]
 pub struct CgroupMetrics {
     #[serde(skip)]
     pub name: String,
+    /// The labels rendered from the `labels` of the matcher.
+    #[serde(skip)]
+    pub labels: Vec<(String, String)>,
+    /// Series that need their own labels, such as the per page size hugetlb metrics or the
+    /// per device blkio metrics.
+    #[serde(skip)]
+    pub series: Vec<LabeledSeries<CgroupSeries>>,
 
+    /// The core cgroup v2 interface files, collected for every v2 cgroup.
+    pub core: Option<CoreStat>,
     pub cpu: Option<CpuStat>,
-    pub cpuacct: Option<CpuAcct>,
+    pub cpuacct: Option<CpuAcctStat>,
     pub cpuset: Option<CpuSet>,
-    pub memory: Option<Memory>,
-    pub blkio: Option<BlkIo>,
+    pub memory: Option<MemoryMetrics>,
+    pub blkio: Option<BlkIoTotals>,
+    pub pids: Option<PidsStat>,
+    pub pressure: Option<Pressure>,
+
+    // The derived memory metrics are flat so that they are named like the cAdvisor ones,
+    // e.g. `container_memory_working_set_bytes` with the `container` namespace.
+    pub memory_working_set_bytes: Option<u64>,
+    pub memory_rss: Option<u64>,
+    pub memory_cache: Option<u64>,
+    pub memory_mapped_file: Option<u64>,
 
     // It would be easier to reuse the `ProcessMetrics struct and use `#[serde(flatten)]`,
     // but the serde uses a the map serializer and serde_prom doesn't support maps yet.
     pub rss: u64,
-    pub utime: u64,
-    pub stime: u64,
-    pub cpu_seconds_total: u64,
+    pub pss_bytes: Option<u64>,
+    pub uss_bytes: Option<u64>,
+    pub swap_bytes: Option<u64>,
+    pub shared_bytes: Option<u64>,
+    pub utime: f64,
+    pub stime: f64,
+    pub cpu_seconds_total: f64,
     pub memory_usage_bytes: u64,
     pub num_fds: u64,
+    pub max_fds: Option<u64>,
+    pub fd_utilization_ratio: Option<f64>,
     pub num_procs: u64,
     pub num_threads: u64,
     pub io_read_bytes_total: u64,
     pub io_write_bytes_total: u64,
+    pub io_read_chars_total: u64,
+    pub io_write_chars_total: u64,
+    pub io_read_syscalls_total: u64,
+    pub io_write_syscalls_total: u64,
+    pub io_cancelled_write_bytes_total: u64,
     pub major_page_faults_total: u64,
     pub minor_page_faults_total: u64,
+    pub voluntary_context_switches_total: u64,
+    pub nonvoluntary_context_switches_total: u64,
+    pub scheduler_wait_seconds_total: f64,
+    pub io_delay_seconds_total: f64,
     pub start_time: Option<i64>,
 }
 
@@ -55,6 +101,8 @@ impl CgroupMetrics {
     pub fn from_cgroup_blocking<E>(
         cgroup: &Cgroup,
         matcher: &CgroupMatcher,
+        metrics_config: &MetricsConfig,
+        filter: &MetricFilter,
         evaluator: &E,
     ) -> anyhow::Result<Self>
     where
@@ -62,59 +110,161 @@ impl CgroupMetrics {
     {
         let mut metrics = CgroupMetrics {
             name: Self::rewrite_name(cgroup, matcher, evaluator)?,
+            labels: Self::render_labels(cgroup, matcher),
             ..Default::default()
         };
 
-        if let Some(ctrl) = cgroup.controller_of::<MemController>() {
-            metrics.memory = Some(ctrl.memory_stat());
+        let reads = |controller| reads_controller(metrics_config, filter, controller);
+        if let Some(ctrl) = cgroup
+            .controller_of::<MemController>()
+            .filter(|_| reads(Controller::Memory))
+        {
+            let memory = if cgroup.v2() {
+                MemoryMetrics::V2(MemoryV2::from_dir(ctrl.path()))
+            } else {
+                MemoryMetrics::V1(ctrl.memory_stat())
+            };
+            if metrics_config.derived_memory {
+                metrics.set_derived_memory(&memory.derived());
+            }
+            metrics.memory = Some(memory);
         }
 
-        if cgroup.v2() {
-            if let Some(ctrl) = cgroup.controller_of::<CpuController>() {
-                metrics.cpu = Some(parse_v2_stat(&ctrl.cpu().stat));
+        if let Some(ctrl) = cgroup
+            .controller_of::<CpuController>()
+            .filter(|_| reads(Controller::Cpu))
+        {
+            let mut cpu = CpuStat::from_controller(ctrl, cgroup.v2());
+            if metrics_config.usec_as_seconds {
+                cpu.usec_as_seconds();
             }
+            metrics.cpu = Some(cpu);
         }
 
-        if let Some(ctrl) = cgroup.controller_of::<CpuAcctController>() {
-            metrics.cpuacct = Some(ctrl.cpuacct());
+        if let Some(ctrl) = cgroup
+            .controller_of::<CpuAcctController>()
+            .filter(|_| reads(Controller::CpuAcct))
+        {
+            let cpuacct = ctrl.cpuacct();
+            let mut stat = CpuAcctStat::from(&cpuacct);
+            if metrics_config.per_cpu_usage {
+                // The series per CPU replace the total, so the metric doesn't mix both.
+                stat.usage_seconds = None;
+                metrics.series.extend(per_cpu_series(&cpuacct));
+            }
+            metrics.cpuacct = Some(stat);
         }
 
-        if let Some(ctrl) = cgroup.controller_of::<BlkIoController>() {
-            metrics.blkio = Some(ctrl.blkio());
+        if let Some(ctrl) = cgroup
+            .controller_of::<BlkIoController>()
+            .filter(|_| reads(Controller::Io))
+        {
+            let (totals, devices) = blkio_metrics(&ctrl.blkio(), cgroup.v2());
+            metrics.blkio = totals;
+            metrics.series.extend(devices);
         }
 
-        let processes_iter = cgroup.procs().into_iter().filter_map(|pid| {
-            let pid = pid.pid.saturating_cast();
-            Process::new(pid).ok()
-        });
+        if let Some(ctrl) = cgroup
+            .controller_of::<HugeTlbController>()
+            .filter(|_| reads(Controller::HugeTlb))
+        {
+            metrics.series.extend(hugetlb_series(ctrl, cgroup.v2()));
+        }
+
+        if let Some(ctrl) = cgroup
+            .controller_of::<PidController>()
+            .filter(|_| reads(Controller::Pids))
+        {
+            metrics.pids = Some(PidsStat::from_controller(ctrl));
+        }
+
+        if let Some(dir) = unified_dir(cgroup).filter(|_| cgroup.v2()) {
+            if reads(Controller::Core) {
+                metrics.core = Some(CoreStat::from_dir(&dir));
+            }
+            if reads(Controller::Pressure) {
+                metrics.pressure = Pressure::from_dir(&dir);
+            }
+        }
+
+        let network = metrics_config.network && filter.allows_prefix("network");
+        let processes_rollup = reads(Controller::Processes);
+        if !network && !processes_rollup {
+            return Ok(metrics);
+        }
+
+        let processes: Vec<Process> = cgroup
+            .procs()
+            .into_iter()
+            .filter_map(|pid| {
+                let pid = pid.pid.saturating_cast();
+                Process::new(pid).ok()
+            })
+            .collect();
 
-        metrics.set_proc_metrics(processes_iter);
+        if network {
+            metrics.series.extend(network_series(&processes));
+        }
+
+        if processes_rollup {
+            metrics.set_proc_metrics(
+                processes.into_iter(),
+                &ProcReads::new(metrics_config, filter),
+            );
+        }
 
         Ok(metrics)
     }
 
+    fn set_derived_memory(&mut self, derived: &DerivedMemory) {
+        self.memory_working_set_bytes = Some(derived.working_set_bytes);
+        self.memory_rss = Some(derived.rss);
+        self.memory_cache = Some(derived.cache);
+        self.memory_mapped_file = Some(derived.mapped_file);
+    }
+
     #[allow(clippy::similar_names)]
-    fn set_proc_metrics(&mut self, procs: impl Iterator<Item = Process>) {
+    fn set_proc_metrics(&mut self, procs: impl Iterator<Item = Process>, reads: &ProcReads) {
         let procs_iter = procs.filter_map(|proc| {
             let mut proc: Proc = proc.try_into().ok()?;
-            proc.gather_remaining_info().ok()?;
+            proc.gather_remaining_info(reads).ok()?;
             Some(proc)
         });
         let metrics = ProcessMetrics::from_processes(procs_iter, "");
 
         self.rss = metrics.rss;
+        self.pss_bytes = metrics.pss_bytes;
+        self.uss_bytes = metrics.uss_bytes;
+        self.swap_bytes = metrics.swap_bytes;
+        self.shared_bytes = metrics.shared_bytes;
         self.memory_usage_bytes = metrics.memory_usage_bytes;
         self.utime = metrics.utime;
         self.stime = metrics.stime;
         self.cpu_seconds_total = metrics.cpu_seconds_total;
         self.num_fds = metrics.num_fds;
+        self.max_fds = metrics.max_fds;
+        self.fd_utilization_ratio = metrics.fd_utilization_ratio;
         self.num_procs = metrics.num_procs;
         self.num_threads = metrics.num_threads;
         self.io_read_bytes_total = metrics.io_read_bytes_total;
         self.io_write_bytes_total = metrics.io_write_bytes_total;
+        self.io_read_chars_total = metrics.io_read_chars_total;
+        self.io_write_chars_total = metrics.io_write_chars_total;
+        self.io_read_syscalls_total = metrics.io_read_syscalls_total;
+        self.io_write_syscalls_total = metrics.io_write_syscalls_total;
+        self.io_cancelled_write_bytes_total = metrics.io_cancelled_write_bytes_total;
         self.major_page_faults_total = metrics.major_page_faults_total;
         self.minor_page_faults_total = metrics.minor_page_faults_total;
+        self.voluntary_context_switches_total = metrics.voluntary_context_switches_total;
+        self.nonvoluntary_context_switches_total = metrics.nonvoluntary_context_switches_total;
+        self.scheduler_wait_seconds_total = metrics.scheduler_wait_seconds_total;
+        self.io_delay_seconds_total = metrics.io_delay_seconds_total;
         self.start_time = metrics.start_time;
+        self.series
+            .extend(metrics.series.into_iter().map(|series| LabeledSeries {
+                labels: series.labels,
+                metrics: CgroupSeries::Process(series.metrics),
+            }));
     }
 
     fn rewrite_name<E>(
@@ -145,14 +295,7 @@ impl CgroupMetrics {
                     }
                 },
                 NameMatcher::Regex(regex) => {
-                    if let Some(captures) = regex.captures(cgroup.path()) {
-                        let mut variables = HashMap::new();
-                        for name in regex.capture_names() {
-                            let Some(name) = name else { continue };
-                            if let Some(matched) = captures.name(name) {
-                                variables.insert(name, matched.as_str().to_string());
-                            }
-                        }
+                    if let Some(variables) = capture_variables(regex, cgroup.path()) {
                         match name {
                             Templated::Name(template) => {
                                 let template = Template::new(template);
@@ -173,56 +316,120 @@ impl CgroupMetrics {
             },
         }
     }
+
+    /// Renders the `labels` of the matcher with the regex capture groups. Like the name, the
+    /// templates are used as they are when there is nothing to capture.
+    fn render_labels(cgroup: &Cgroup, matcher: &CgroupMatcher) -> Vec<(String, String)> {
+        match &matcher.path {
+            NameMatcher::Regex(regex) => capture_variables(regex, cgroup.path())
+                .map(|variables| render_labels(&matcher.labels, &variables)),
+            NameMatcher::Glob(_) => None,
+        }
+        .unwrap_or_else(|| {
+            matcher
+                .labels
+                .iter()
+                .map(|(label, template)| (label.clone(), template.clone()))
+                .collect()
+        })
+    }
+}
+
+/// Returns whether a controller is read. The controllers that are turned off in `controllers`, or
+/// that none of the included metrics need, are not read at all.
+fn reads_controller(
+    metrics_config: &MetricsConfig,
+    filter: &MetricFilter,
+    controller: Controller,
+) -> bool {
+    let field = match controller {
+        Controller::Memory => "memory",
+        Controller::Cpu => "cpu",
+        Controller::CpuAcct => "cpuacct",
+        Controller::Io => "blkio",
+        Controller::HugeTlb => "hugetlb",
+        Controller::Pids => "pids",
+        Controller::Core => "core",
+        Controller::Pressure => "pressure",
+        Controller::Processes => {
+            return metrics_config.reads(controller) && filter.allows_any(PROCESS_METRICS);
+        }
+    };
+    metrics_config.reads(controller)
+        && (filter.is_empty() || controller_metrics(field).any(|name| filter.allows(name)))
+}
+
+/// The names of the metrics read from a controller: the metadata entries under its field, e.g.
+/// `memory_*` for `memory`, except the process rollup metrics that share the prefix, like
+/// `memory_usage_bytes` and `cpu_seconds_total`.
+fn controller_metrics(field: &str) -> impl Iterator<Item = &'static str> + '_ {
+    METADATA
+        .iter()
+        .filter(move |(key, _)| {
+            key.strip_prefix(field)
+                .is_some_and(|rest| rest.starts_with('_'))
+        })
+        .map(|(key, descriptor)| descriptor.rename.unwrap_or(*key))
+        .filter(|name| !PROCESS_METRICS.contains(name))
+}
+
+/// Returns the named capture groups of the regex, or `None` when it doesn't match the path.
+fn capture_variables<'r>(regex: &'r Regex, path: &str) -> Option<HashMap<&'r str, String>> {
+    let captures = regex.captures(path)?;
+    let mut variables = HashMap::new();
+    for name in regex.capture_names() {
+        let Some(name) = name else { continue };
+        if let Some(matched) = captures.name(name) {
+            variables.insert(name, matched.as_str().to_string());
+        }
+    }
+    Some(variables)
 }
 
 impl Named for CgroupMetrics {
     fn name(&self) -> &str {
         &self.name
     }
-}
 
-#[derive(Serialize, Default)]
-pub struct CpuStat {
-    pub usage_usec: Option<u64>,
-    pub user_usec: Option<u64>,
-    pub system_usec: Option<u64>,
-    pub nice_usec: Option<u64>,
-    pub nr_periods: Option<u64>,
-    pub nr_throttled: Option<u64>,
-    pub throttled_usec: Option<u64>,
-    pub nr_bursts: Option<u64>,
-    pub burst_usec: Option<u64>,
+    fn labels(&self) -> &[(String, String)] {
+        &self.labels
+    }
 }
 
-fn parse_v2_stat(stat: &str) -> CpuStat {
-    let mut v2_stat = CpuStat::default();
-    for line in stat.lines() {
-        let mut parts = line.split_whitespace();
-        if let Some(key) = parts.next() {
-            if let Some(value) = parts.next() {
-                match key {
-                    "usage_usec" => v2_stat.usage_usec = value.parse().ok(),
-                    "user_usec" => v2_stat.user_usec = value.parse().ok(),
-                    "system_usec" => v2_stat.system_usec = value.parse().ok(),
-                    "nice_usec" => v2_stat.nice_usec = value.parse().ok(),
-                    "nr_periods" => v2_stat.nr_periods = value.parse().ok(),
-                    "nr_throttled" => v2_stat.nr_throttled = value.parse().ok(),
-                    "throttled_usec" => v2_stat.throttled_usec = value.parse().ok(),
-                    "nr_bursts" => v2_stat.nr_bursts = value.parse().ok(),
-                    "burst_usec" => v2_stat.burst_usec = value.parse().ok(),
-                    _ => {}
-                }
-            }
-        }
+impl Labeled for CgroupMetrics {
+    type Series = CgroupSeries;
+
+    fn labeled_series(&self) -> &[LabeledSeries<CgroupSeries>] {
+        &self.series
     }
-    v2_stat
+}
+
+/// A group of cgroup metrics that is emitted with labels of its own.
+/// The field name of each variant becomes the metric name prefix.
+#[derive(Serialize)]
+#[serde(untagged)]
+pub enum CgroupSeries {
+    HugeTlb {
+        hugetlb: HugeTlbStat,
+    },
+    BlkIo {
+        blkio: Box<BlkIoDevice>,
+    },
+    CpuAcct {
+        cpuacct: CpuAcctUsage,
+    },
+    Network {
+        network: NetworkInterface,
+    },
+    /// The labeled series of the processes in the cgroup, such as the socket counts.
+    Process(ProcessSeries),
 }
 
 #[cfg(test)]
 mod tests {
     use cgroups_explorer::Explorer;
     use cgroups_exporter_config::RewriteCgroupName;
-    use std::collections::HashMap;
+    use std::collections::{BTreeMap, HashMap};
 
     use crate::shell::MockEvaluator;
 
@@ -240,6 +447,7 @@ mod tests {
             rewrite: Some(RewriteCgroupName::RemovePrefix {
                 remove_prefix: "user.slice/user-1000.slice/".into(),
             }),
+            labels: BTreeMap::new(),
         };
         let evaluator = MockEvaluator::new();
 
@@ -247,7 +455,13 @@ mod tests {
             .include(vec![filter.into()])
             .build()?;
         for cgroup in explorer.iter_cgroups() {
-            let metrics = CgroupMetrics::from_cgroup_blocking(&cgroup, &matcher, &evaluator)?;
+            let metrics = CgroupMetrics::from_cgroup_blocking(
+                &cgroup,
+                &matcher,
+                &MetricsConfig::default(),
+                &MetricFilter::default(),
+                &evaluator,
+            )?;
             let mut labels = global_labels.clone();
             labels.insert("cgroup", &metrics.name);
 
@@ -263,4 +477,94 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn controllers_gate_reads() -> anyhow::Result<()> {
+        let metrics_config = MetricsConfig {
+            controllers: Some(vec![Controller::Memory, Controller::Pids]),
+            ..Default::default()
+        };
+        let no_filter = MetricFilter::default();
+        assert!(reads_controller(
+            &metrics_config,
+            &no_filter,
+            Controller::Memory
+        ));
+        assert!(reads_controller(
+            &metrics_config,
+            &no_filter,
+            Controller::Pids
+        ));
+        assert!(!reads_controller(
+            &metrics_config,
+            &no_filter,
+            Controller::Cpu
+        ));
+        assert!(!reads_controller(
+            &metrics_config,
+            &no_filter,
+            Controller::Io
+        ));
+        assert!(!reads_controller(
+            &metrics_config,
+            &no_filter,
+            Controller::Processes
+        ));
+
+        // The process rollup metrics named like the controller metrics don't need the controllers.
+        let rollup = MetricFilter::new(
+            &[
+                "memory_usage_bytes".to_string(),
+                "cpu_seconds_total".to_string(),
+            ],
+            &[],
+        )?;
+        assert!(!reads_controller(
+            &MetricsConfig::default(),
+            &rollup,
+            Controller::Memory
+        ));
+        assert!(!reads_controller(
+            &MetricsConfig::default(),
+            &rollup,
+            Controller::Cpu
+        ));
+        assert!(reads_controller(
+            &MetricsConfig::default(),
+            &rollup,
+            Controller::Processes
+        ));
+
+        let cpuacct = MetricFilter::new(&["cpuacct_*".to_string()], &[])?;
+        assert!(!reads_controller(
+            &MetricsConfig::default(),
+            &cpuacct,
+            Controller::Cpu
+        ));
+        assert!(reads_controller(
+            &MetricsConfig::default(),
+            &cpuacct,
+            Controller::CpuAcct
+        ));
+
+        let working_set = MetricFilter::new(&["memory_working_set_bytes".to_string()], &[])?;
+        assert!(reads_controller(
+            &MetricsConfig::default(),
+            &working_set,
+            Controller::Memory
+        ));
+
+        let exclude_memory = MetricFilter::new(&[], &["memory_*".to_string()])?;
+        assert!(!reads_controller(
+            &metrics_config,
+            &exclude_memory,
+            Controller::Memory
+        ));
+        assert!(reads_controller(
+            &MetricsConfig::default(),
+            &no_filter,
+            Controller::Processes
+        ));
+        Ok(())
+    }
 }
@@ -1,4 +1,14 @@
+mod blkio;
+mod cpu;
+mod cpuacct;
 mod discover;
+mod fs;
+mod hugetlb;
+mod lifecycle;
+mod memory;
 mod metrics;
+mod network;
+mod pids;
+mod pressure;
 
 pub use discover::discover_cgroups_metrics;
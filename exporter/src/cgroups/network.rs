@@ -0,0 +1,178 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::LazyLock,
+};
+
+use procfs::{net::DeviceStatus, process::Process};
+use serde::Serialize;
+
+use crate::{cgroups::metrics::CgroupSeries, procs::net_namespace, render::LabeledSeries};
+
+const NETNS_LABEL: &str = "netns";
+const INTERFACE_LABEL: &str = "interface";
+
+/// The network namespace of the init process. Cgroups in this namespace would only report the
+/// host interfaces, so they are skipped.
+static HOST_NETNS: LazyLock<Option<u64>> =
+    LazyLock::new(|| Process::new(1).ok().as_ref().and_then(net_namespace));
+
+/// The counters of a single network interface, named like the cAdvisor `container_network_*`
+/// metrics.
+#[derive(Serialize, Default, Debug, PartialEq)]
+pub struct NetworkInterface {
+    pub receive_bytes: u64,
+    pub receive_packets: u64,
+    pub receive_errors: u64,
+    pub receive_packets_dropped: u64,
+    pub transmit_bytes: u64,
+    pub transmit_packets: u64,
+    pub transmit_errors: u64,
+    pub transmit_packets_dropped: u64,
+}
+
+impl NetworkInterface {
+    fn add(&mut self, status: &DeviceStatus) {
+        self.receive_bytes += status.recv_bytes;
+        self.receive_packets += status.recv_packets;
+        self.receive_errors += status.recv_errs;
+        self.receive_packets_dropped += status.recv_drop;
+        self.transmit_bytes += status.sent_bytes;
+        self.transmit_packets += status.sent_packets;
+        self.transmit_errors += status.sent_errs;
+        self.transmit_packets_dropped += status.sent_drop;
+    }
+}
+
+/// Returns one series per network interface of each network namespace of the cgroup, labeled
+/// with the inode of the namespace and the interface name. `/proc/<pid>/net/dev` is read from
+/// one process of each namespace.
+///
+/// Cgroups that share a namespace, like the containers of a pod, report the same counters with
+/// the same `netns` label, so they can be told apart from the traffic of separate namespaces.
+pub fn network_series(processes: &[Process]) -> Vec<LabeledSeries<CgroupSeries>> {
+    let mut namespaces = BTreeMap::new();
+    for process in processes {
+        let Some(netns) = net_namespace(process) else {
+            continue;
+        };
+        if Some(netns) == *HOST_NETNS || namespaces.contains_key(&netns) {
+            continue;
+        }
+        // Processes may exit while we read them, so another member of the namespace is tried.
+        if let Ok(devices) = process.dev_status() {
+            namespaces.insert(netns, devices);
+        }
+    }
+    interface_series(namespaces)
+}
+
+/// Returns one series per interface of each namespace.
+fn interface_series(
+    namespaces: BTreeMap<u64, HashMap<String, DeviceStatus>>,
+) -> Vec<LabeledSeries<CgroupSeries>> {
+    let mut series = Vec::new();
+    for (netns, devices) in namespaces {
+        let mut interfaces: BTreeMap<String, NetworkInterface> = BTreeMap::new();
+        for (name, status) in devices {
+            interfaces.entry(name).or_default().add(&status);
+        }
+        series.extend(
+            interfaces
+                .into_iter()
+                .map(|(name, interface)| LabeledSeries {
+                    labels: vec![(NETNS_LABEL, netns.to_string()), (INTERFACE_LABEL, name)],
+                    metrics: CgroupSeries::Network { network: interface },
+                }),
+        );
+    }
+    series
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use procfs::{FromBufRead as _, net::InterfaceDeviceStatus};
+
+    use super::*;
+
+    fn parse_net_dev(content: &str) -> HashMap<String, DeviceStatus> {
+        InterfaceDeviceStatus::from_buf_read(content.as_bytes())
+            .unwrap()
+            .0
+    }
+
+    #[test]
+    fn interfaces_are_labeled_with_their_namespace() {
+        let header = "Inter-|   Receive                                                |  Transmit\n \
+                      face |bytes    packets errs drop fifo frame compressed multicast|bytes    packets errs drop fifo colls carrier compressed\n";
+        let first = format!(
+            "{header}    lo:     100       2    0    0    0     0          0         0      100       2    0    0    0     0       0          0\n  \
+             eth0:    1000      10    1    2    0     0          0         0      500       5    0    1    0     0       0          0\n"
+        );
+        let second = format!(
+            "{header}  eth0:    3000      30    0    0    0     0          0         0     1500      15    2    0    0     0       0          0\n"
+        );
+
+        let series = interface_series(BTreeMap::from([
+            (4_026_531_992, parse_net_dev(&first)),
+            (4_026_532_500, parse_net_dev(&second)),
+        ]));
+        let interfaces: Vec<_> = series
+            .iter()
+            .map(|series| {
+                let CgroupSeries::Network { network } = &series.metrics else {
+                    panic!("expected a network series");
+                };
+                (series.labels.clone(), network)
+            })
+            .collect();
+        assert_eq!(
+            interfaces,
+            vec![
+                (
+                    vec![
+                        (NETNS_LABEL, "4026531992".to_string()),
+                        (INTERFACE_LABEL, "eth0".to_string())
+                    ],
+                    &NetworkInterface {
+                        receive_bytes: 1000,
+                        receive_packets: 10,
+                        receive_errors: 1,
+                        receive_packets_dropped: 2,
+                        transmit_bytes: 500,
+                        transmit_packets: 5,
+                        transmit_packets_dropped: 1,
+                        ..Default::default()
+                    }
+                ),
+                (
+                    vec![
+                        (NETNS_LABEL, "4026531992".to_string()),
+                        (INTERFACE_LABEL, "lo".to_string())
+                    ],
+                    &NetworkInterface {
+                        receive_bytes: 100,
+                        receive_packets: 2,
+                        transmit_bytes: 100,
+                        transmit_packets: 2,
+                        ..Default::default()
+                    }
+                ),
+                (
+                    vec![
+                        (NETNS_LABEL, "4026532500".to_string()),
+                        (INTERFACE_LABEL, "eth0".to_string())
+                    ],
+                    &NetworkInterface {
+                        receive_bytes: 3000,
+                        receive_packets: 30,
+                        transmit_bytes: 1500,
+                        transmit_packets: 15,
+                        transmit_errors: 2,
+                        ..Default::default()
+                    }
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,74 @@
+use std::path::Path;
+
+use cgroups_rs::{Controller as _, pid::PidController};
+use serde::Serialize;
+
+use crate::cgroups::fs::{flat_keyed, read_file};
+
+/// Metrics of the pids controller. The interface files are the same on cgroup v1 and v2.
+#[derive(Serialize, Default, Debug, PartialEq)]
+pub struct PidsStat {
+    /// The number of processes and threads in the cgroup and its descendants.
+    pub current: Option<u64>,
+    /// The process number limit. Left out when there is no limit, i.e. `pids.max` is `max`.
+    pub max: Option<u64>,
+    /// How many times a fork or clone failed because the limit was hit.
+    pub events_max: Option<u64>,
+}
+
+impl PidsStat {
+    pub fn from_controller(ctrl: &PidController) -> Self {
+        Self::from_dir(ctrl.path())
+    }
+
+    fn from_dir(dir: &Path) -> Self {
+        Self::parse(
+            read_file(dir, "pids.current").as_deref(),
+            read_file(dir, "pids.max").as_deref(),
+            read_file(dir, "pids.events").as_deref(),
+        )
+    }
+
+    fn parse(current: Option<&str>, max: Option<&str>, events: Option<&str>) -> Self {
+        let number = |content: &str| content.trim().parse().ok();
+        PidsStat {
+            current: current.and_then(number),
+            // `max` doesn't parse as a number, which leaves the limit out.
+            max: max.and_then(number),
+            events_max: events.and_then(|content| {
+                flat_keyed(content).find_map(|(key, value)| (key == "max").then_some(value))
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn parse_pids_files() {
+        assert_eq!(
+            PidsStat::parse(Some("42\n"), Some("1024\n"), Some("max 3\n")),
+            PidsStat {
+                current: Some(42),
+                max: Some(1024),
+                events_max: Some(3),
+            }
+        );
+    }
+
+    #[test]
+    fn parse_pids_without_limit() {
+        assert_eq!(
+            PidsStat::parse(Some("7\n"), Some("max\n"), None),
+            PidsStat {
+                current: Some(7),
+                max: None,
+                events_max: None,
+            }
+        );
+    }
+}
@@ -0,0 +1,123 @@
+use std::path::Path;
+
+use serde::Serialize;
+
+use crate::{cgroups::fs::read_file, units::usec_to_seconds};
+
+/// Pressure Stall Information of a cgroup, read from `cpu.pressure`, `memory.pressure` and
+/// `io.pressure`. Only available on cgroup v2 with a kernel built with `CONFIG_PSI`.
+#[derive(Serialize, Default)]
+pub struct Pressure {
+    pub cpu: Option<PressureStat>,
+    pub memory: Option<PressureStat>,
+    pub io: Option<PressureStat>,
+}
+
+/// The contents of a single `*.pressure` file.
+#[derive(Serialize, Default, Debug, PartialEq)]
+pub struct PressureStat {
+    /// The share of time in which at least some tasks were stalled on the resource.
+    pub some: Option<PressureLine>,
+    /// The share of time in which all non-idle tasks were stalled on the resource.
+    /// The kernel reports it for CPU only on the cgroup level.
+    pub full: Option<PressureLine>,
+}
+
+#[derive(Serialize, Default, Debug, PartialEq)]
+pub struct PressureLine {
+    pub avg10: f64,
+    pub avg60: f64,
+    pub avg300: f64,
+    /// The total stall time in seconds. The kernel reports it in microseconds.
+    pub total: f64,
+}
+
+impl Pressure {
+    /// Reads the pressure files from a cgroup v2 directory.
+    /// Returns `None` if none of the files could be read, e.g. when PSI is disabled.
+    pub fn from_dir(dir: &Path) -> Option<Self> {
+        let pressure = Pressure {
+            cpu: read_pressure_file(dir, "cpu.pressure"),
+            memory: read_pressure_file(dir, "memory.pressure"),
+            io: read_pressure_file(dir, "io.pressure"),
+        };
+        if pressure.cpu.is_none() && pressure.memory.is_none() && pressure.io.is_none() {
+            return None;
+        }
+        Some(pressure)
+    }
+}
+
+fn read_pressure_file(dir: &Path, name: &str) -> Option<PressureStat> {
+    read_file(dir, name).map(|content| parse_pressure(&content))
+}
+
+fn parse_pressure(content: &str) -> PressureStat {
+    let mut stat = PressureStat::default();
+    for line in content.lines() {
+        let mut parts = line.split_whitespace();
+        let Some(kind) = parts.next() else { continue };
+        let mut pressure_line = PressureLine::default();
+        for part in parts {
+            let Some((key, value)) = part.split_once('=') else {
+                continue;
+            };
+            match key {
+                "avg10" => pressure_line.avg10 = value.parse().unwrap_or_default(),
+                "avg60" => pressure_line.avg60 = value.parse().unwrap_or_default(),
+                "avg300" => pressure_line.avg300 = value.parse().unwrap_or_default(),
+                "total" => {
+                    pressure_line.total = value
+                        .parse::<u64>()
+                        .map(usec_to_seconds)
+                        .unwrap_or_default();
+                }
+                _ => {}
+            }
+        }
+        match kind {
+            "some" => stat.some = Some(pressure_line),
+            "full" => stat.full = Some(pressure_line),
+            _ => {}
+        }
+    }
+    stat
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn parse_pressure_some_and_full() {
+        let content = "some avg10=1.50 avg60=0.25 avg300=0.00 total=2500000\n\
+                       full avg10=0.00 avg60=0.00 avg300=0.00 total=1000\n";
+        assert_eq!(
+            parse_pressure(content),
+            PressureStat {
+                some: Some(PressureLine {
+                    avg10: 1.5,
+                    avg60: 0.25,
+                    avg300: 0.0,
+                    total: 2.5,
+                }),
+                full: Some(PressureLine {
+                    avg10: 0.0,
+                    avg60: 0.0,
+                    avg300: 0.0,
+                    total: 0.001,
+                }),
+            }
+        );
+    }
+
+    #[test]
+    fn parse_pressure_some_only() {
+        let content = "some avg10=0.00 avg60=0.00 avg300=0.00 total=0\n";
+        let stat = parse_pressure(content);
+        assert!(stat.some.is_some());
+        assert!(stat.full.is_none());
+    }
+}
@@ -0,0 +1,406 @@
+//! Include and exclude globs over metric names.
+//!
+//! The metrics are filtered while they are serialized: the fields of excluded metrics are
+//! serialized as `None`, which the Prometheus serializer skips like any other missing value.
+
+use std::collections::HashMap;
+
+use glob::Pattern;
+use serde::{
+    Serialize, Serializer,
+    ser::{SerializeStruct, SerializeStructVariant},
+};
+use serde_prom::MetricDescriptor;
+
+const GLOB_METACHARS: [char; 3] = ['*', '?', '['];
+
+type Metadata<'s> = HashMap<&'s str, MetricDescriptor<'s>>;
+
+/// A mirror of the `include` and `exclude` lists of `MetricsConfig` with parsed globs. The
+/// globs are matched against the metric names without the namespace, e.g.
+/// `cpu_usage_usec_total`.
+#[derive(Debug, Clone, Default)]
+pub struct MetricFilter {
+    include: Vec<Pattern>,
+    exclude: Vec<Pattern>,
+}
+
+impl MetricFilter {
+    pub fn new(include: &[String], exclude: &[String]) -> anyhow::Result<Self> {
+        let parse = |globs: &[String]| -> anyhow::Result<Vec<Pattern>> {
+            globs.iter().map(|glob| Ok(Pattern::new(glob)?)).collect()
+        };
+        Ok(Self {
+            include: parse(include)?,
+            exclude: parse(exclude)?,
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.include.is_empty() && self.exclude.is_empty()
+    }
+
+    pub fn allows(&self, name: &str) -> bool {
+        (self.include.is_empty() || self.include.iter().any(|glob| glob.matches(name)))
+            && !self.exclude.iter().any(|glob| glob.matches(name))
+    }
+
+    /// Returns whether any metric under the prefix, such as `memory` for `memory_*`, could be
+    /// allowed. This is used to skip reading controllers, so it errs on the side of `true`.
+    pub fn allows_prefix(&self, prefix: &str) -> bool {
+        let prefix = format!("{prefix}_");
+        let could_include = self.include.is_empty()
+            || self.include.iter().any(|glob| {
+                let literal = literal_prefix(glob);
+                literal.starts_with(&prefix) || prefix.starts_with(literal)
+            });
+        let excludes_all = self.exclude.iter().any(|glob| {
+            let literal = literal_prefix(glob);
+            prefix.starts_with(literal) && &glob.as_str()[literal.len()..] == "*"
+        });
+        could_include && !excludes_all
+    }
+
+    /// Returns whether any of the metrics could be allowed.
+    pub fn allows_any(&self, names: &[&str]) -> bool {
+        names.iter().any(|name| self.allows(name))
+    }
+
+    /// Wraps a value so that only the allowed metrics are serialized.
+    pub fn wrap<'f, T: Serialize + ?Sized>(
+        &'f self,
+        value: &'f T,
+        metadata: &'f Metadata<'f>,
+    ) -> Filtered<'f, T> {
+        Filtered {
+            value,
+            context: Context {
+                filter: self,
+                select: None,
+                metadata,
+                path: String::new(),
+            },
+        }
+    }
+
+    /// Like `wrap`, but also leaves out the allowed metrics for which `select` returns `false`.
+    /// `select` is called with the name of each allowed metric as it is serialized.
+    pub fn wrap_selected<'f, T: Serialize + ?Sized>(
+        &'f self,
+        value: &'f T,
+        metadata: &'f Metadata<'f>,
+        select: &'f dyn Fn(&str) -> bool,
+    ) -> Filtered<'f, T> {
+        Filtered {
+            value,
+            context: Context {
+                filter: self,
+                select: Some(select),
+                metadata,
+                path: String::new(),
+            },
+        }
+    }
+}
+
+/// The part of the glob before the first wildcard.
+fn literal_prefix(glob: &Pattern) -> &str {
+    let glob = glob.as_str();
+    glob.find(GLOB_METACHARS)
+        .map_or(glob, |index| &glob[..index])
+}
+
+pub struct Filtered<'f, T: ?Sized> {
+    value: &'f T,
+    context: Context<'f>,
+}
+
+#[derive(Clone)]
+struct Context<'f> {
+    filter: &'f MetricFilter,
+    select: Option<&'f dyn Fn(&str) -> bool>,
+    metadata: &'f Metadata<'f>,
+    /// The field names from the top-level struct joined with `_`, which is how the metrics are
+    /// named.
+    path: String,
+}
+
+impl<'f> Context<'f> {
+    fn wrap<'v, T: ?Sized>(&self, value: &'v T) -> Filtered<'v, T>
+    where
+        'f: 'v,
+    {
+        Filtered {
+            value,
+            context: self.clone(),
+        }
+    }
+
+    /// Whether the metric at the path is serialized. Its name is the path after the rename from
+    /// the metadata.
+    fn allows_path(&self) -> bool {
+        let name = self
+            .metadata
+            .get(self.path.as_str())
+            .and_then(|descriptor| descriptor.rename)
+            .unwrap_or(&self.path);
+        self.filter.allows(name) && self.select.is_none_or(|select| select(name))
+    }
+
+    fn field(&self, key: &str) -> Self {
+        let path = if self.path.is_empty() {
+            key.to_string()
+        } else {
+            format!("{}_{key}", self.path)
+        };
+        Self {
+            path,
+            ..self.clone()
+        }
+    }
+}
+
+impl<T: Serialize + ?Sized> Serialize for Filtered<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.value.serialize(FilterSerializer {
+            inner: serializer,
+            context: self.context.clone(),
+        })
+    }
+}
+
+struct FilterSerializer<'f, S> {
+    inner: S,
+    context: Context<'f>,
+}
+
+/// Filters the fields of a struct by their path.
+pub struct FilterCompound<'f, C> {
+    inner: C,
+    context: Context<'f>,
+}
+
+macro_rules! serialize_leaf {
+    ($($method:ident($ty:ty)),* $(,)?) => {
+        $(
+            fn $method(self, value: $ty) -> Result<S::Ok, S::Error> {
+                if self.context.allows_path() {
+                    self.inner.$method(value)
+                } else {
+                    self.inner.serialize_none()
+                }
+            }
+        )*
+    };
+}
+
+impl<'f, S: Serializer> Serializer for FilterSerializer<'f, S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+    type SerializeSeq = S::SerializeSeq;
+    type SerializeTuple = S::SerializeTuple;
+    type SerializeTupleStruct = S::SerializeTupleStruct;
+    type SerializeTupleVariant = S::SerializeTupleVariant;
+    type SerializeMap = S::SerializeMap;
+    type SerializeStruct = FilterCompound<'f, S::SerializeStruct>;
+    type SerializeStructVariant = FilterCompound<'f, S::SerializeStructVariant>;
+
+    serialize_leaf!(
+        serialize_bool(bool),
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_f32(f32),
+        serialize_f64(f64),
+        serialize_char(char),
+        serialize_str(&str),
+        serialize_bytes(&[u8]),
+    );
+
+    fn serialize_none(self) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_none()
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_some(&self.context.wrap(value))
+    }
+
+    fn serialize_unit(self) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_unit()
+    }
+
+    fn serialize_unit_struct(self, name: &'static str) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_unit_struct(name)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+    ) -> Result<S::Ok, S::Error> {
+        self.inner
+            .serialize_unit_variant(name, variant_index, variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<S::Ok, S::Error> {
+        self.inner
+            .serialize_newtype_struct(name, &self.context.wrap(value))
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_newtype_variant(
+            name,
+            variant_index,
+            variant,
+            &self.context.wrap(value),
+        )
+    }
+
+    // Sequences and maps are not metrics on their own, so they are passed through.
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, S::Error> {
+        self.inner.serialize_seq(len)
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, S::Error> {
+        self.inner.serialize_tuple(len)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, S::Error> {
+        self.inner.serialize_tuple_struct(name, len)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, S::Error> {
+        self.inner
+            .serialize_tuple_variant(name, variant_index, variant, len)
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, S::Error> {
+        self.inner.serialize_map(len)
+    }
+
+    fn serialize_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, S::Error> {
+        Ok(FilterCompound {
+            inner: self.inner.serialize_struct(name, len)?,
+            context: self.context,
+        })
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStructVariant, S::Error> {
+        Ok(FilterCompound {
+            inner: self
+                .inner
+                .serialize_struct_variant(name, variant_index, variant, len)?,
+            context: self.context,
+        })
+    }
+}
+
+impl<C: SerializeStruct> SerializeStruct for FilterCompound<'_, C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), C::Error> {
+        self.inner
+            .serialize_field(key, &self.context.field(key).wrap(value))
+    }
+
+    fn skip_field(&mut self, key: &'static str) -> Result<(), C::Error> {
+        self.inner.skip_field(key)
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C: SerializeStructVariant> SerializeStructVariant for FilterCompound<'_, C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), C::Error> {
+        self.inner
+            .serialize_field(key, &self.context.field(key).wrap(value))
+    }
+
+    fn skip_field(&mut self, key: &'static str) -> Result<(), C::Error> {
+        self.inner.skip_field(key)
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filter(include: &[&str], exclude: &[&str]) -> MetricFilter {
+        let globs = |globs: &[&str]| globs.iter().map(ToString::to_string).collect::<Vec<_>>();
+        MetricFilter::new(&globs(include), &globs(exclude)).unwrap()
+    }
+
+    #[test]
+    fn include_and_exclude() {
+        let filter = filter(&["memory_*", "cpu_usage_*"], &["memory_numa_*"]);
+        assert!(filter.allows("memory_usage_in_bytes"));
+        assert!(filter.allows("cpu_usage_usec_total"));
+        assert!(!filter.allows("memory_numa_total"));
+        assert!(!filter.allows("blkio_io_serviced_total"));
+
+        assert!(filter.allows_prefix("memory"));
+        assert!(filter.allows_prefix("cpu"));
+        assert!(!filter.allows_prefix("cpuacct"));
+        assert!(!filter.allows_prefix("blkio"));
+
+        let filter = self::filter(&[], &["blkio_*", "pressure*"]);
+        assert!(filter.allows_prefix("memory"));
+        assert!(!filter.allows_prefix("blkio"));
+        assert!(!filter.allows_prefix("pressure"));
+        assert!(MetricFilter::default().allows_prefix("blkio"));
+    }
+}
@@ -0,0 +1,100 @@
+//! Labels from the config. Static labels are expanded once when the config is loaded, and the
+//! labels of the matchers are rendered for each match.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    env,
+    sync::LazyLock,
+};
+
+use anyhow::Context as _;
+use new_string_template::template::Template;
+use regex::{Captures, Regex};
+
+const HOSTNAME_PATH: &str = "/proc/sys/kernel/hostname";
+
+/// Matches `${VAR}` environment variables and the built-in `{hostname}`.
+static VARIABLE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\$\{(?<env>\w+)\}|\{hostname\}").unwrap());
+
+/// Expands the values of static labels. Fails if an environment variable is not set, so a
+/// missing variable in a deployment doesn't silently produce empty labels.
+pub fn expand_labels(labels: &BTreeMap<String, String>) -> anyhow::Result<Vec<(String, String)>> {
+    labels
+        .iter()
+        .map(|(label, value)| {
+            let value = expand(value, |name| env::var(name).ok(), hostname)
+                .with_context(|| format!("Failed to expand the value of label `{label}`"))?;
+            Ok((label.clone(), value))
+        })
+        .collect()
+}
+
+/// Renders the label templates of a matcher with the variables of a regex match, the same way as
+/// the name of the group.
+pub fn render_labels(
+    labels: &BTreeMap<String, String>,
+    variables: &HashMap<&str, String>,
+) -> Vec<(String, String)> {
+    labels
+        .iter()
+        .map(|(label, template)| {
+            (
+                label.clone(),
+                Template::new(template).render_nofail(variables),
+            )
+        })
+        .collect()
+}
+
+fn hostname() -> anyhow::Result<String> {
+    let hostname = std::fs::read_to_string(HOSTNAME_PATH).context("Failed to read hostname")?;
+    Ok(hostname.trim().to_string())
+}
+
+fn expand(
+    value: &str,
+    env: impl Fn(&str) -> Option<String>,
+    hostname: impl Fn() -> anyhow::Result<String>,
+) -> anyhow::Result<String> {
+    let mut error = None;
+    let expanded = VARIABLE.replace_all(value, |captures: &Captures| {
+        let result = match captures.name("env") {
+            Some(name) => env(name.as_str())
+                .with_context(|| format!("Environment variable `{}` is not set", name.as_str())),
+            None => hostname(),
+        };
+        result.unwrap_or_else(|err| {
+            error.get_or_insert(err);
+            String::new()
+        })
+    });
+    match error {
+        Some(err) => Err(err),
+        None => Ok(expanded.into_owned()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn expand_env_and_hostname() {
+        let env = |name: &str| (name == "NODE_NAME").then(|| "node-1".to_string());
+        let hostname = || Ok("host-1".to_string());
+
+        assert_eq!(
+            expand("${NODE_NAME}/{hostname}", env, hostname).unwrap(),
+            "node-1/host-1"
+        );
+        assert_eq!(expand("prod", env, hostname).unwrap(), "prod");
+        assert_eq!(
+            expand("{name}-{hostname}", env, hostname).unwrap(),
+            "{name}-host-1"
+        );
+        assert!(expand("${MISSING}", env, hostname).is_err());
+    }
+}
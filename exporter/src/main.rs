@@ -20,16 +20,20 @@ use crate::{
 
 mod cgroups;
 mod file_watcher;
+mod filter;
+mod labels;
 mod logging;
 mod matcher;
 mod metadata;
 #[allow(clippy::ref_option)]
 mod procs;
+mod relabel;
 mod render;
 mod server;
 mod shell;
 #[cfg(test)]
 mod tests;
+mod units;
 
 const DEFAULT_LISTEN_ADDR: &str = "127.0.0.1:9753";
 
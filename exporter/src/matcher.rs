@@ -1,16 +1,29 @@
-use std::fmt;
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+    sync::Arc,
+};
 
+use anyhow::Context as _;
 use cgroups_exporter_config::{
     CgroupConfig, CgroupMatch, Config, MetricsConfig, NameMatch, ProcessConfig, ProcessMatch,
     RewriteCgroupName, ShellCommandsConfig, Templated,
 };
 use regex::Regex;
 
+use crate::{
+    labels::expand_labels,
+    procs::ProcReads,
+    render::{SERIES_LABELS, SeriesRules, name_label},
+};
+
 #[derive(Debug, Clone)]
 pub struct MatchableConfig {
     pub cgroups: Vec<MatchableCgroupConfig>,
     pub processes: Vec<MatchableProcessConfig>,
     pub shell_commands: ShellCommandsConfig,
+    /// The expanded labels added to every metric.
+    pub labels: Vec<(String, String)>,
 }
 
 /// A mirror of `CgroupConfig` but with parsed Regex and no serialization.
@@ -18,6 +31,8 @@ pub struct MatchableConfig {
 pub struct MatchableCgroupConfig {
     pub match_by: CgroupMatcher,
     pub metrics: MetricsConfig,
+    /// The parsed `metrics.relabel`, `metrics.include` and `metrics.exclude`.
+    pub rules: Arc<SeriesRules>,
 }
 
 /// A mirror of `ProcessConfig` but with parsed Regex and no serialization.
@@ -25,6 +40,10 @@ pub struct MatchableCgroupConfig {
 pub struct MatchableProcessConfig {
     pub match_by: ProcessMatcher,
     pub metrics: MetricsConfig,
+    /// The parsed `metrics.relabel`, `metrics.include` and `metrics.exclude`.
+    pub rules: Arc<SeriesRules>,
+    /// The files of the matched processes that the allowed metrics need.
+    pub reads: ProcReads,
 }
 
 /// A mirror of `CgroupMatcher` but with parsed Regex and no serialization.
@@ -32,15 +51,32 @@ pub struct MatchableProcessConfig {
 pub struct CgroupMatcher {
     pub path: NameMatcher,
     pub rewrite: Option<RewriteCgroupName>,
+    pub labels: BTreeMap<String, String>,
 }
 
 /// A mirror of `ProcessMatch` but with parsed Regex and no serialization.
 #[derive(Debug, Clone)]
 pub enum ProcessMatcher {
-    Exe { exe: NameMatcher, name: String },
-    ExeBase { exe_base: NameMatcher, name: String },
-    Comm { comm: NameMatcher, name: String },
-    Cmdline { cmdline: NameMatcher, name: String },
+    Exe {
+        exe: NameMatcher,
+        name: String,
+        labels: BTreeMap<String, String>,
+    },
+    ExeBase {
+        exe_base: NameMatcher,
+        name: String,
+        labels: BTreeMap<String, String>,
+    },
+    Comm {
+        comm: NameMatcher,
+        name: String,
+        labels: BTreeMap<String, String>,
+    },
+    Cmdline {
+        cmdline: NameMatcher,
+        name: String,
+        labels: BTreeMap<String, String>,
+    },
 }
 
 /// A mirror of `NameMatch` but with parsed Regex and no serialization.
@@ -68,6 +104,7 @@ impl TryFrom<CgroupMatch> for CgroupMatcher {
         let me = Self {
             path: value.path.try_into()?,
             rewrite: value.rewrite,
+            labels: value.labels,
         };
         if matches!(&me.path, NameMatcher::Glob(_))
             && matches!(
@@ -90,21 +127,33 @@ impl TryFrom<ProcessMatch> for ProcessMatcher {
 
     fn try_from(value: ProcessMatch) -> Result<Self, Self::Error> {
         match value {
-            ProcessMatch::Exe { name, exe } => Ok(Self::Exe {
+            ProcessMatch::Exe { name, exe, labels } => Ok(Self::Exe {
                 exe: exe.try_into()?,
                 name,
+                labels,
             }),
-            ProcessMatch::ExeBase { name, exe_base } => Ok(Self::ExeBase {
+            ProcessMatch::ExeBase {
+                name,
+                exe_base,
+                labels,
+            } => Ok(Self::ExeBase {
                 exe_base: exe_base.try_into()?,
                 name,
+                labels,
             }),
-            ProcessMatch::Comm { name, comm } => Ok(Self::Comm {
+            ProcessMatch::Comm { name, comm, labels } => Ok(Self::Comm {
                 comm: comm.try_into()?,
                 name,
+                labels,
             }),
-            ProcessMatch::Cmdline { name, cmdline } => Ok(Self::Cmdline {
+            ProcessMatch::Cmdline {
+                name,
+                cmdline,
+                labels,
+            } => Ok(Self::Cmdline {
                 cmdline: cmdline.try_into()?,
                 name,
+                labels,
             }),
         }
     }
@@ -116,7 +165,8 @@ impl TryFrom<CgroupConfig> for MatchableCgroupConfig {
     fn try_from(value: CgroupConfig) -> Result<Self, Self::Error> {
         Ok(Self {
             match_by: value.match_by.try_into()?,
-            metrics: value.metrics,
+            rules: Arc::new(SeriesRules::try_from(&value.metrics)?),
+            metrics: expand_metrics_labels(value.metrics)?,
         })
     }
 }
@@ -125,9 +175,12 @@ impl TryFrom<ProcessConfig> for MatchableProcessConfig {
     type Error = anyhow::Error;
 
     fn try_from(value: ProcessConfig) -> Result<Self, Self::Error> {
+        let rules = SeriesRules::try_from(&value.metrics)?;
         Ok(Self {
             match_by: value.match_by.try_into()?,
-            metrics: value.metrics,
+            reads: ProcReads::new(&value.metrics, &rules.filter),
+            rules: Arc::new(rules),
+            metrics: expand_metrics_labels(value.metrics)?,
         })
     }
 }
@@ -136,22 +189,81 @@ impl TryFrom<Config> for MatchableConfig {
     type Error = anyhow::Error;
 
     fn try_from(value: Config) -> Result<Self, Self::Error> {
+        let labels = expand_labels(&value.labels)?;
         let mut cgroups = Vec::new();
         for cgroup in value.cgroups {
-            cgroups.push(cgroup.try_into()?);
+            let cgroup: MatchableCgroupConfig = cgroup.try_into()?;
+            check_label_names(&labels, &cgroup.metrics, &cgroup.match_by.labels).with_context(
+                || {
+                    format!(
+                        "Invalid labels for the cgroups matching {}",
+                        cgroup.match_by.path
+                    )
+                },
+            )?;
+            cgroups.push(cgroup);
         }
         let mut processes = Vec::new();
         for process in value.processes {
-            processes.push(process.try_into()?);
+            let process: MatchableProcessConfig = process.try_into()?;
+            check_label_names(&labels, &process.metrics, process.match_by.labels()).with_context(
+                || {
+                    format!(
+                        "Invalid labels for the process group {}",
+                        process.match_by.name()
+                    )
+                },
+            )?;
+            processes.push(process);
         }
         Ok(Self {
             cgroups,
             processes,
             shell_commands: value.shell_commands,
+            labels,
         })
     }
 }
 
+/// Fails when two sources of labels set a label with the same name, which would write the label
+/// twice on the same series. None of them takes precedence over the others.
+fn check_label_names(
+    common_labels: &[(String, String)],
+    metrics: &MetricsConfig,
+    matcher_labels: &BTreeMap<String, String>,
+) -> anyhow::Result<()> {
+    let mut sources = HashMap::new();
+    let labels = std::iter::once((name_label(metrics), "the name label"))
+        .chain(SERIES_LABELS.iter().map(|label| (*label, "a series label")))
+        .chain(
+            matcher_labels
+                .keys()
+                .map(|label| (label.as_str(), "`match.labels`")),
+        )
+        .chain(
+            metrics
+                .labels
+                .keys()
+                .map(|label| (label.as_str(), "`metrics.labels`")),
+        )
+        .chain(
+            common_labels
+                .iter()
+                .map(|(label, _)| (label.as_str(), "the top-level `labels`")),
+        );
+    for (label, source) in labels {
+        if let Some(other) = sources.insert(label, source) {
+            anyhow::bail!("Label `{label}` is set by both {other} and {source}");
+        }
+    }
+    Ok(())
+}
+
+fn expand_metrics_labels(mut metrics: MetricsConfig) -> anyhow::Result<MetricsConfig> {
+    metrics.labels = expand_labels(&metrics.labels)?.into_iter().collect();
+    Ok(metrics)
+}
+
 impl ProcessMatcher {
     /// Returns the group name for this matcher from config.
     #[must_use]
@@ -164,6 +276,17 @@ impl ProcessMatcher {
         }
     }
 
+    /// Returns the extra label templates from config for this matcher.
+    #[must_use]
+    pub fn labels(&self) -> &BTreeMap<String, String> {
+        match self {
+            Self::Cmdline { labels, .. }
+            | Self::Comm { labels, .. }
+            | Self::Exe { labels, .. }
+            | Self::ExeBase { labels, .. } => labels,
+        }
+    }
+
     /// Returns the glob/regex matcher from config for this `ProcessMatcher`.
     #[must_use]
     pub fn matcher(&self) -> &NameMatcher {
@@ -184,3 +307,30 @@ impl fmt::Display for NameMatcher {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matcher_labels_must_not_collide() {
+        let metrics = MetricsConfig {
+            label_map: HashMap::from([("name".to_string(), "service".to_string())]),
+            ..Default::default()
+        };
+        let labels = |label: &str| BTreeMap::from([(label.to_string(), "{x}".to_string())]);
+
+        assert!(check_label_names(&[], &metrics, &labels("name")).is_ok());
+        assert!(check_label_names(&[], &metrics, &labels("service")).is_err());
+        assert!(check_label_names(&[], &metrics, &labels("device")).is_err());
+
+        let common = [("node".to_string(), "node-1".to_string())];
+        assert!(check_label_names(&common, &metrics, &labels("env")).is_ok());
+        assert!(check_label_names(&common, &metrics, &labels("node")).is_err());
+        let metrics = MetricsConfig {
+            labels: BTreeMap::from([("env".to_string(), "prod".to_string())]),
+            ..metrics
+        };
+        assert!(check_label_names(&common, &metrics, &labels("env")).is_err());
+    }
+}
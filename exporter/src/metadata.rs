@@ -2,1703 +2,3117 @@ use std::{collections::HashMap, sync::LazyLock};
 
 use serde_prom::{MetricDescriptor, MetricType};
 
-pub static METADATA: phf::Map<&'static str, MetricDescriptor<'static>> = phf::phf_map! {
+/// The descriptors of the metrics, in the form the serializers take them.
+pub static METADATA: LazyLock<HashMap<&'static str, MetricDescriptor<'static>>> =
+    LazyLock::new(|| {
+        DESCRIPTORS
+            .entries()
+            .map(|(key, descriptor)| {
+                debug_assert!(
+                    descriptor.unit_matches_name(key),
+                    "the unit of {key} doesn't match its name"
+                );
+                (*key, descriptor.metric.clone())
+            })
+            .collect()
+    });
+
+/// Describes a metric, and the unit of its values when they are durations.
+pub struct Descriptor {
+    pub metric: MetricDescriptor<'static>,
+    /// The values are converted when they are collected, see the `units` module, so that every
+    /// metric named `_seconds` is in seconds.
+    pub unit: Option<Unit>,
+}
+
+/// The unit of the metrics whose values are durations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Unit {
+    Seconds,
+    Milliseconds,
+    Microseconds,
+    Nanoseconds,
+}
+
+impl Descriptor {
+    /// Whether the name and the help of the metric agree with its unit. Durations that aren't
+    /// in seconds must not be named like they are.
+    fn unit_matches_name(&self, key: &str) -> bool {
+        let name = self.metric.rename.unwrap_or(key);
+        let seconds = name.contains("_seconds") || self.metric.help.contains("in seconds");
+        let usec = name.contains("_usec") || self.metric.help.contains("in microseconds");
+        match self.unit {
+            Some(Unit::Seconds) => !usec,
+            Some(Unit::Microseconds) => !seconds,
+            Some(Unit::Milliseconds | Unit::Nanoseconds) | None => !seconds && !usec,
+        }
+    }
+}
+
+/// The descriptors of the metrics, by the names of the fields they are serialized from.
+static DESCRIPTORS: phf::Map<&'static str, Descriptor> = phf::phf_map! {
     // PROCESS METRICS
-    "rss" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "Resident Set Size in bytes",
-        labels: vec![],
-        rename: None,
-    },
-    "utime" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "User CPU time in seconds",
-        labels: vec![],
-        rename: Some("utime_seconds_total"),
-    },
-    "stime" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "System CPU time in seconds",
-        labels: vec![],
-        rename: Some("stime_seconds_total"),
-    },
-    "cpu_seconds_total" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Total CPU time in seconds",
-        labels: vec![],
-        rename: None,
-    },
-    "memory_usage_bytes" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "Memory usage in bytes",
-        labels: vec![],
-        rename: None,
-    },
-    "num_fds" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "Number of file descriptors",
-        labels: vec![],
-        rename: None,
-    },
-    "num_procs" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "Number of processes",
-        labels: vec![],
-        rename: None,
-    },
-    "num_threads" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "Number of threads",
-        labels: vec![],
-        rename: None,
-    },
-    "io_read_bytes_total" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Number of bytes read",
-        labels: vec![],
-        rename: None,
-    },
-    "io_write_bytes_total" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Number of bytes written",
-        labels: vec![],
-        rename: None,
-    },
-    "major_page_faults_total" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Number of major page faults",
-        labels: vec![],
-        rename: None,
-    },
-    "minor_page_faults_total" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Number of minor page faults",
-        labels: vec![],
-        rename: None,
-    },
-    "start_time" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "Start time in seconds since epoch",
-        labels: vec![],
-        rename: None,
+    "rss" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Resident Set Size in bytes",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "pss_bytes" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Proportional Set Size in bytes",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "uss_bytes" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Unique Set Size in bytes",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "swap_bytes" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Swapped out memory in bytes",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "shared_bytes" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Resident memory shared with other processes in bytes",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "utime" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "User CPU time in seconds",
+            labels: vec![],
+            rename: Some("utime_seconds_total"),
+        },
+        unit: Some(Unit::Seconds),
+    },
+    "stime" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "System CPU time in seconds",
+            labels: vec![],
+            rename: Some("stime_seconds_total"),
+        },
+        unit: Some(Unit::Seconds),
+    },
+    "cpu_seconds_total" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Total CPU time in seconds",
+            labels: vec![],
+            rename: None,
+        },
+        unit: Some(Unit::Seconds),
+    },
+    "memory_usage_bytes" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Memory usage in bytes",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "num_fds" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Number of file descriptors",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "max_fds" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Open files limit of the most saturated process",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "fd_utilization_ratio" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Highest ratio of open file descriptors to the open files limit",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    // One series per fd type, labeled with `type`.
+    "num_fds_by_type" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Number of file descriptors by type",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "num_procs" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Number of processes",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    // One series per state, labeled with `state`.
+    "procs_by_state" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Number of processes by state",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "oldest_zombie_age_seconds" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Time the oldest zombie process has been a zombie, in seconds",
+            labels: vec![],
+            rename: None,
+        },
+        unit: Some(Unit::Seconds),
+    },
+    "num_threads" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Number of threads",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "io_read_bytes_total" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Number of bytes read",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "io_write_bytes_total" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Number of bytes written",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "io_read_chars_total" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Number of bytes read by read syscalls, including the page cache",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "io_write_chars_total" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Number of bytes written by write syscalls, including the page cache",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "io_read_syscalls_total" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Number of read syscalls",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "io_write_syscalls_total" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Number of write syscalls",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "io_cancelled_write_bytes_total" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Number of bytes whose writeback was cancelled",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "major_page_faults_total" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Number of major page faults",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "minor_page_faults_total" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Number of minor page faults",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "voluntary_context_switches_total" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Number of voluntary context switches",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "nonvoluntary_context_switches_total" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Number of involuntary context switches",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "scheduler_wait_seconds_total" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Time spent waiting on a run queue in seconds",
+            labels: vec![],
+            rename: None,
+        },
+        unit: Some(Unit::Seconds),
+    },
+    "io_delay_seconds_total" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Time spent waiting for block IO in seconds",
+            labels: vec![],
+            rename: None,
+        },
+        unit: Some(Unit::Seconds),
+    },
+    "start_time" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Start time in seconds since epoch",
+            labels: vec![],
+            rename: None,
+        },
+        unit: Some(Unit::Seconds),
+    },
+    // One series per protocol and state, labeled with `proto` and `state`.
+    "sockets" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Number of TCP and UDP sockets by protocol and state",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    // CGROUP CORE METRICS (cgroup.stat, cgroup.events and cgroup.freeze, cgroup v2 only)
+    "core_nr_descendants" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Number of visible descendant control groups.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "core_nr_dying_descendants" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Number of removed descendant control groups that are still pinned by some resource, such as page cache.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "core_populated" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "1 if the control group or any of its descendants has live processes, 0 otherwise.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "core_frozen" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "1 if the control group is frozen, 0 otherwise.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "core_freeze" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "1 if the control group was requested to be frozen with cgroup.freeze, 0 otherwise.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
     },
     // CGROUP CPU METRICS
-    "cpu_usage_usec" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "CPU usage in microseconds",
-        labels: vec![],
-        rename: Some("cpu_usage_usec_total"),
-    },
-    "cpu_user_usec" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "User CPU time in microseconds",
-        labels: vec![],
-        rename: Some("cpu_user_usec_total"),
-    },
-    "cpu_system_usec" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "System CPU time in microseconds",
-        labels: vec![],
-        rename: Some("cpu_system_usec_total"),
-    },
-    "cpu_nice_usec" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "",
-        labels: vec![],
-        rename: Some("cpu_nice_usec_total"),
-    },
-    "cpu_nr_periods" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "",
-        labels: vec![],
-        rename: Some("cpu_nr_periods_total"),
-    },
-    "cpu_nr_throttled" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "",
-        labels: vec![],
-        rename: Some("cpu_nr_throttled_total"),
-    },
-    "cpu_throttled_usec" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "",
-        labels: vec![],
-        rename: Some("cpu_throttled_usec_total"),
-    },
-    "cpu_nr_bursts" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "",
-        labels: vec![],
-        rename: Some("cpu_nr_bursts_total"),
-    },
-    "cpu_burst_usec" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "",
-        labels: vec![],
-        rename: Some("cpu_burst_usec_total"),
+    "cpu_usage_usec" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "CPU usage in microseconds",
+            labels: vec![],
+            rename: Some("cpu_usage_usec_total"),
+        },
+        unit: Some(Unit::Microseconds),
+    },
+    "cpu_user_usec" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "User CPU time in microseconds",
+            labels: vec![],
+            rename: Some("cpu_user_usec_total"),
+        },
+        unit: Some(Unit::Microseconds),
+    },
+    "cpu_system_usec" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "System CPU time in microseconds",
+            labels: vec![],
+            rename: Some("cpu_system_usec_total"),
+        },
+        unit: Some(Unit::Microseconds),
+    },
+    "cpu_nice_usec" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "CPU time of niced tasks in microseconds",
+            labels: vec![],
+            rename: Some("cpu_nice_usec_total"),
+        },
+        unit: Some(Unit::Microseconds),
+    },
+    "cpu_nr_periods" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Number of CPU bandwidth enforcement periods that have elapsed",
+            labels: vec![],
+            rename: Some("cpu_nr_periods_total"),
+        },
+        unit: None,
+    },
+    "cpu_nr_throttled" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Number of periods in which the control group was throttled",
+            labels: vec![],
+            rename: Some("cpu_nr_throttled_total"),
+        },
+        unit: None,
+    },
+    "cpu_throttled_usec" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Total time the control group was throttled in microseconds",
+            labels: vec![],
+            rename: Some("cpu_throttled_usec_total"),
+        },
+        unit: Some(Unit::Microseconds),
+    },
+    "cpu_nr_bursts" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Number of periods in which a burst occurred",
+            labels: vec![],
+            rename: Some("cpu_nr_bursts_total"),
+        },
+        unit: None,
+    },
+    "cpu_burst_usec" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Total time spent bursting beyond the quota in microseconds",
+            labels: vec![],
+            rename: Some("cpu_burst_usec_total"),
+        },
+        unit: Some(Unit::Microseconds),
+    },
+    // The counters above in seconds, with usecAsSeconds.
+    "cpu_usage_seconds" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "CPU usage in seconds",
+            labels: vec![],
+            rename: Some("cpu_usage_seconds_total"),
+        },
+        unit: Some(Unit::Seconds),
+    },
+    "cpu_user_seconds" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "User CPU time in seconds",
+            labels: vec![],
+            rename: Some("cpu_user_seconds_total"),
+        },
+        unit: Some(Unit::Seconds),
+    },
+    "cpu_system_seconds" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "System CPU time in seconds",
+            labels: vec![],
+            rename: Some("cpu_system_seconds_total"),
+        },
+        unit: Some(Unit::Seconds),
+    },
+    "cpu_nice_seconds" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "CPU time of niced tasks in seconds",
+            labels: vec![],
+            rename: Some("cpu_nice_seconds_total"),
+        },
+        unit: Some(Unit::Seconds),
+    },
+    "cpu_throttled_seconds" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Total time the control group was throttled in seconds",
+            labels: vec![],
+            rename: Some("cpu_throttled_seconds_total"),
+        },
+        unit: Some(Unit::Seconds),
+    },
+    "cpu_burst_seconds" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Total time spent bursting beyond the quota in seconds",
+            labels: vec![],
+            rename: Some("cpu_burst_seconds_total"),
+        },
+        unit: Some(Unit::Seconds),
+    },
+    "cpu_quota_usec" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "The CPU time in microseconds the control group may use in each period. Not reported when unlimited.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: Some(Unit::Microseconds),
+    },
+    "cpu_period_usec" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "The length of the CPU quota period in microseconds.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: Some(Unit::Microseconds),
+    },
+    "cpu_limit_cores" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "The number of CPUs the control group may use, derived from the quota and the period.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "cpu_weight" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "The relative CPU weight of the control group. cgroup v2 only.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "cpu_weight_nice" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "The relative CPU weight of the control group as a nice value. cgroup v2 only.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "cpu_shares" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "The relative CPU shares of the control group. cgroup v1 only.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    // CGROUP CPUACCT METRICS (cgroup v1 only)
+    "cpuacct_usage_seconds" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "CPU time consumed by the control group in seconds. With perCpuUsage, one series per CPU and mode instead.",
+            labels: vec![],
+            rename: Some("cpuacct_usage_seconds_total"),
+        },
+        unit: Some(Unit::Seconds),
+    },
+    "cpuacct_usage_user_seconds" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "User CPU time consumed by the control group in seconds.",
+            labels: vec![],
+            rename: Some("cpuacct_usage_user_seconds_total"),
+        },
+        unit: Some(Unit::Seconds),
+    },
+    "cpuacct_usage_sys_seconds" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "System CPU time consumed by the control group in seconds.",
+            labels: vec![],
+            rename: Some("cpuacct_usage_sys_seconds_total"),
+        },
+        unit: Some(Unit::Seconds),
     },
     // CGROUP MEMORY METRICS (cgroups_rs::memory::Memory)
     // Memory struct fields
-    "memory_fail_cnt" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many times the limit has been hit.",
-        labels: vec![],
-        rename: Some("memory_fail_cnt_total"),
-    },
-    "memory_limit_in_bytes" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The limit in bytes of the memory usage of the control group's tasks.",
-        labels: vec![],
-        rename: None,
-    },
-    "memory_usage_in_bytes" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The current usage of memory by the control group's tasks.",
-        labels: vec![],
-        rename: None,
-    },
-    "memory_max_usage_in_bytes" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The maximum observed usage of memory by the control group's tasks.",
-        labels: vec![],
-        rename: None,
-    },
-    "memory_move_charge_at_immigrate" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "Whether moving charges at immigrate is allowed.",
-        labels: vec![],
-        rename: None,
+    "memory_fail_cnt" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many times the limit has been hit.",
+            labels: vec![],
+            rename: Some("memory_fail_cnt_total"),
+        },
+        unit: None,
+    },
+    "memory_limit_in_bytes" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "The limit in bytes of the memory usage of the control group's tasks.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_usage_in_bytes" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "The current usage of memory by the control group's tasks.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_max_usage_in_bytes" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "The maximum observed usage of memory by the control group's tasks.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_move_charge_at_immigrate" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Whether moving charges at immigrate is allowed.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
     },
     // Nested: NumaStat fields (prefixed with "memory_numa_stat_")
-    "memory_numa_stat_total_pages" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "Total amount of pages used by the control group.",
-        labels: vec![],
-        rename: None,
-    },
-    "memory_numa_stat_total_pages_per_node" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "Total amount of pages used by the control group, broken down by NUMA node.",
-        labels: vec![],
-        rename: None,
-    },
-    "memory_numa_stat_file_pages" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "Total amount of file pages used by the control group.",
-        labels: vec![],
-        rename: None,
-    },
-    "memory_numa_stat_file_pages_per_node" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "Total amount of file pages used by the control group, broken down by NUMA node.",
-        labels: vec![],
-        rename: None,
-    },
-    "memory_numa_stat_anon_pages" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "Total amount of anonymous pages used by the control group.",
-        labels: vec![],
-        rename: None,
-    },
-    "memory_numa_stat_anon_pages_per_node" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "Total amount of anonymous pages used by the control group, broken down by NUMA node.",
-        labels: vec![],
-        rename: None,
-    },
-    "memory_numa_stat_unevictable_pages" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "Total amount of unevictable pages used by the control group.",
-        labels: vec![],
-        rename: None,
-    },
-    "memory_numa_stat_unevictable_pages_per_node" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "Total amount of unevictable pages used by the control group, broken down by NUMA node.",
-        labels: vec![],
-        rename: None,
-    },
-    "memory_numa_stat_hierarchical_total_pages" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "Same as total_pages, but includes the descendant control groups' number as well.",
-        labels: vec![],
-        rename: None,
-    },
-    "memory_numa_stat_hierarchical_total_pages_per_node" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "Same as total_pages_per_node, but includes the descendant control groups' number as well.",
-        labels: vec![],
-        rename: None,
-    },
-    "memory_numa_stat_hierarchical_file_pages" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "Same as file_pages, but includes the descendant control groups' number as well.",
-        labels: vec![],
-        rename: None,
-    },
-    "memory_numa_stat_hierarchical_file_pages_per_node" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "Same as file_pages_per_node, but includes the descendant control groups' number as well.",
-        labels: vec![],
-        rename: None,
-    },
-    "memory_numa_stat_hierarchical_anon_pages" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "Same as anon_pages, but includes the descendant control groups' number as well.",
-        labels: vec![],
-        rename: None,
-    },
-    "memory_numa_stat_hierarchical_anon_pages_per_node" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "Same as anon_pages_per_node, but includes the descendant control groups' number as well.",
-        labels: vec![],
-        rename: None,
-    },
-    "memory_numa_stat_hierarchical_unevictable_pages" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "Same as unevictable, but includes the descendant control groups' number as well.",
-        labels: vec![],
-        rename: None,
-    },
-    "memory_numa_stat_hierarchical_unevictable_pages_per_node" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "Same as unevictable_per_node, but includes the descendant control groups' number as well.",
-        labels: vec![],
-        rename: None,
+    "memory_numa_stat_total_pages" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Total amount of pages used by the control group.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_numa_stat_total_pages_per_node" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Total amount of pages used by the control group, broken down by NUMA node.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_numa_stat_file_pages" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Total amount of file pages used by the control group.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_numa_stat_file_pages_per_node" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Total amount of file pages used by the control group, broken down by NUMA node.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_numa_stat_anon_pages" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Total amount of anonymous pages used by the control group.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_numa_stat_anon_pages_per_node" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Total amount of anonymous pages used by the control group, broken down by NUMA node.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_numa_stat_unevictable_pages" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Total amount of unevictable pages used by the control group.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_numa_stat_unevictable_pages_per_node" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Total amount of unevictable pages used by the control group, broken down by NUMA node.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_numa_stat_hierarchical_total_pages" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Same as total_pages, but includes the descendant control groups' number as well.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_numa_stat_hierarchical_total_pages_per_node" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Same as total_pages_per_node, but includes the descendant control groups' number as well.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_numa_stat_hierarchical_file_pages" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Same as file_pages, but includes the descendant control groups' number as well.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_numa_stat_hierarchical_file_pages_per_node" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Same as file_pages_per_node, but includes the descendant control groups' number as well.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_numa_stat_hierarchical_anon_pages" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Same as anon_pages, but includes the descendant control groups' number as well.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_numa_stat_hierarchical_anon_pages_per_node" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Same as anon_pages_per_node, but includes the descendant control groups' number as well.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_numa_stat_hierarchical_unevictable_pages" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Same as unevictable, but includes the descendant control groups' number as well.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_numa_stat_hierarchical_unevictable_pages_per_node" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Same as unevictable_per_node, but includes the descendant control groups' number as well.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
     },
     // Nested: OomControl fields (prefixed with "memory_oom_control_")
-    "memory_oom_control_oom_kill_disable" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "If true, the OOM killer has been disabled for the tasks in this control group.",
-        labels: vec![],
-        rename: None,
-    },
-    "memory_oom_control_under_oom" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "Is the OOM killer currently running for the tasks in the control group?",
-        labels: vec![],
-        rename: None,
-    },
-    "memory_oom_control_oom_kill" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many tasks were killed by the OOM killer so far.",
-        labels: vec![],
-        rename: Some("memory_oom_control_oom_kill_total"),
+    "memory_oom_control_oom_kill_disable" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "If true, the OOM killer has been disabled for the tasks in this control group.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_oom_control_under_oom" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Is the OOM killer currently running for the tasks in the control group?",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_oom_control_oom_kill" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many tasks were killed by the OOM killer so far.",
+            labels: vec![],
+            rename: Some("memory_oom_control_oom_kill_total"),
+        },
+        unit: None,
     },
     // Back to Memory struct fields
-    "memory_soft_limit_in_bytes" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "Allows setting a limit to memory usage which is enforced when the system detects memory pressure.",
-        labels: vec![],
-        rename: None,
+    "memory_soft_limit_in_bytes" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Allows setting a limit to memory usage which is enforced when the system detects memory pressure.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
     },
     // Nested: MemoryStat fields (prefixed with "memory_stat_")
-    "memory_stat_cache" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "Cache memory usage by the control group's tasks.",
-        labels: vec![],
-        rename: None,
-    },
-    "memory_stat_rss" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "Resident set size memory usage.",
-        labels: vec![],
-        rename: None,
-    },
-    "memory_stat_rss_huge" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "Huge pages of resident set size memory usage.",
-        labels: vec![],
-        rename: None,
-    },
-    "memory_stat_shmem" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "Shared memory usage.",
-        labels: vec![],
-        rename: None,
-    },
-    "memory_stat_mapped_file" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "Mapped file memory usage.",
-        labels: vec![],
-        rename: None,
-    },
-    "memory_stat_dirty" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "Dirty pages count.",
-        labels: vec![],
-        rename: None,
-    },
-    "memory_stat_writeback" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "Pages in writeback.",
-        labels: vec![],
-        rename: None,
-    },
-    "memory_stat_swap" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "Swap usage.",
-        labels: vec![],
-        rename: None,
-    },
-    "memory_stat_pgpgin" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Number of pages paged in.",
-        labels: vec![],
-        rename: Some("memory_stat_pgpgin_total"),
-    },
-    "memory_stat_pgpgout" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Number of pages paged out.",
-        labels: vec![],
-        rename: Some("memory_stat_pgpgout_total"),
-    },
-    "memory_stat_pgfault" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Number of page faults.",
-        labels: vec![],
-        rename: Some("memory_stat_pgfault_total"),
-    },
-    "memory_stat_pgmajfault" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Number of major page faults.",
-        labels: vec![],
-        rename: Some("memory_stat_pgmajfault_total"),
-    },
-    "memory_stat_inactive_anon" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "Inactive anonymous pages.",
-        labels: vec![],
-        rename: None,
-    },
-    "memory_stat_active_anon" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "Active anonymous pages.",
-        labels: vec![],
-        rename: None,
-    },
-    "memory_stat_inactive_file" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "Inactive file pages.",
-        labels: vec![],
-        rename: None,
-    },
-    "memory_stat_active_file" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "Active file pages.",
-        labels: vec![],
-        rename: None,
-    },
-    "memory_stat_unevictable" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "Unevictable pages.",
-        labels: vec![],
-        rename: None,
-    },
-    "memory_stat_hierarchical_memory_limit" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "Hierarchical memory limit for the control group's tasks.",
-        labels: vec![],
-        rename: None,
-    },
-    "memory_stat_hierarchical_memsw_limit" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "Hierarchical memory+swap limit for the control group's tasks.",
-        labels: vec![],
-        rename: None,
-    },
-    "memory_stat_total_cache" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "Total cache memory usage including descendant control groups.",
-        labels: vec![],
-        rename: None,
-    },
-    "memory_stat_total_rss" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "Total resident set size memory usage including descendant control groups.",
-        labels: vec![],
-        rename: None,
-    },
-    "memory_stat_total_rss_huge" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "Total huge pages of RSS memory usage including descendant control groups.",
-        labels: vec![],
-        rename: None,
-    },
-    "memory_stat_total_shmem" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "Total shared memory usage including descendant control groups.",
-        labels: vec![],
-        rename: None,
-    },
-    "memory_stat_total_mapped_file" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "Total mapped file memory usage including descendant control groups.",
-        labels: vec![],
-        rename: None,
-    },
-    "memory_stat_total_dirty" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "Total dirty pages count including descendant control groups.",
-        labels: vec![],
-        rename: None,
-    },
-    "memory_stat_total_writeback" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "Total writeback pages including descendant control groups.",
-        labels: vec![],
-        rename: None,
-    },
-    "memory_stat_total_swap" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "Total swap usage including descendant control groups.",
-        labels: vec![],
-        rename: None,
-    },
-    "memory_stat_total_pgpgin" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Total number of pages paged in including descendant control groups.",
-        labels: vec![],
-        rename: Some("memory_stat_total_pgpgin_total"),
-    },
-    "memory_stat_total_pgpgout" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Total number of pages paged out including descendant control groups.",
-        labels: vec![],
-        rename: Some("memory_stat_total_pgpgout_total"),
-    },
-    "memory_stat_total_pgfault" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Total number of page faults including descendant control groups.",
-        labels: vec![],
-        rename: Some("memory_stat_total_pgfault_total"),
-    },
-    "memory_stat_total_pgmajfault" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Total number of major page faults including descendant control groups.",
-        labels: vec![],
-        rename: Some("memory_stat_total_pgmajfault_total"),
-    },
-    "memory_stat_total_inactive_anon" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "Total inactive anonymous pages including descendant control groups.",
-        labels: vec![],
-        rename: None,
-    },
-    "memory_stat_total_active_anon" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "Total active anonymous pages including descendant control groups.",
-        labels: vec![],
-        rename: None,
-    },
-    "memory_stat_total_inactive_file" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "Total inactive file pages including descendant control groups.",
-        labels: vec![],
-        rename: None,
-    },
-    "memory_stat_total_active_file" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "Total active file pages including descendant control groups.",
-        labels: vec![],
-        rename: None,
-    },
-    "memory_stat_total_unevictable" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "Total unevictable pages including descendant control groups.",
-        labels: vec![],
-        rename: None,
+    "memory_stat_cache" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Cache memory usage by the control group's tasks.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_stat_rss" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Resident set size memory usage.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_stat_rss_huge" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Huge pages of resident set size memory usage.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_stat_shmem" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Shared memory usage.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_stat_mapped_file" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Mapped file memory usage.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_stat_dirty" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Dirty pages count.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_stat_writeback" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Pages in writeback.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_stat_swap" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Swap usage.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_stat_pgpgin" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Number of pages paged in.",
+            labels: vec![],
+            rename: Some("memory_stat_pgpgin_total"),
+        },
+        unit: None,
+    },
+    "memory_stat_pgpgout" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Number of pages paged out.",
+            labels: vec![],
+            rename: Some("memory_stat_pgpgout_total"),
+        },
+        unit: None,
+    },
+    "memory_stat_pgfault" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Number of page faults.",
+            labels: vec![],
+            rename: Some("memory_stat_pgfault_total"),
+        },
+        unit: None,
+    },
+    "memory_stat_pgmajfault" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Number of major page faults.",
+            labels: vec![],
+            rename: Some("memory_stat_pgmajfault_total"),
+        },
+        unit: None,
+    },
+    "memory_stat_inactive_anon" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Inactive anonymous pages.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_stat_active_anon" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Active anonymous pages.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_stat_inactive_file" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Inactive file pages.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_stat_active_file" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Active file pages.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_stat_unevictable" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Unevictable pages.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_stat_hierarchical_memory_limit" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Hierarchical memory limit for the control group's tasks.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_stat_hierarchical_memsw_limit" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Hierarchical memory+swap limit for the control group's tasks.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_stat_total_cache" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Total cache memory usage including descendant control groups.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_stat_total_rss" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Total resident set size memory usage including descendant control groups.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_stat_total_rss_huge" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Total huge pages of RSS memory usage including descendant control groups.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_stat_total_shmem" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Total shared memory usage including descendant control groups.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_stat_total_mapped_file" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Total mapped file memory usage including descendant control groups.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_stat_total_dirty" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Total dirty pages count including descendant control groups.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_stat_total_writeback" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Total writeback pages including descendant control groups.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_stat_total_swap" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Total swap usage including descendant control groups.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_stat_total_pgpgin" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Total number of pages paged in including descendant control groups.",
+            labels: vec![],
+            rename: Some("memory_stat_total_pgpgin_total"),
+        },
+        unit: None,
+    },
+    "memory_stat_total_pgpgout" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Total number of pages paged out including descendant control groups.",
+            labels: vec![],
+            rename: Some("memory_stat_total_pgpgout_total"),
+        },
+        unit: None,
+    },
+    "memory_stat_total_pgfault" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Total number of page faults including descendant control groups.",
+            labels: vec![],
+            rename: Some("memory_stat_total_pgfault_total"),
+        },
+        unit: None,
+    },
+    "memory_stat_total_pgmajfault" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Total number of major page faults including descendant control groups.",
+            labels: vec![],
+            rename: Some("memory_stat_total_pgmajfault_total"),
+        },
+        unit: None,
+    },
+    "memory_stat_total_inactive_anon" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Total inactive anonymous pages including descendant control groups.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_stat_total_active_anon" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Total active anonymous pages including descendant control groups.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_stat_total_inactive_file" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Total inactive file pages including descendant control groups.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_stat_total_active_file" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Total active file pages including descendant control groups.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_stat_total_unevictable" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Total unevictable pages including descendant control groups.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
     },
     // Final Memory struct fields
-    "memory_swappiness" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "Set the tendency of the kernel to swap out parts of the address space consumed by the control group's tasks.",
-        labels: vec![],
-        rename: None,
-    },
-    "memory_use_hierarchy" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "If set, under OOM conditions the kernel will try to reclaim memory from the children of the offending process.",
-        labels: vec![],
-        rename: None,
+    "memory_swappiness" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Set the tendency of the kernel to swap out parts of the address space consumed by the control group's tasks.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_use_hierarchy" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "If set, under OOM conditions the kernel will try to reclaim memory from the children of the offending process.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    // CGROUP V2 MEMORY METRICS (memory.stat, memory.events, limits and protections)
+    // Shared fields like shmem, pgfault and the LRU lists reuse the v1 descriptors above.
+    "memory_high_bytes" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "The memory usage throttle limit of the control group in bytes. Reported as 2^64-1 when set to max.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_low_bytes" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "The best-effort memory protection of the control group in bytes. Reported as 2^64-1 when set to max.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_min_bytes" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "The hard memory protection of the control group in bytes. Reported as 2^64-1 when set to max.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_swap_usage_bytes" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "The current swap usage of the control group in bytes.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_swap_limit_bytes" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "The swap usage limit of the control group in bytes. Reported as 2^64-1 when set to max.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_stat_anon" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Anonymous memory, such as brk, sbrk, and mmap(MAP_ANONYMOUS).",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_stat_file" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Memory used to cache filesystem data, including tmpfs and shared memory.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_stat_kernel" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Total kernel memory, including kernel stacks, page tables, slab and percpu memory.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_stat_kernel_stack" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Memory allocated to kernel stacks.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_stat_pagetables" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Memory allocated for page tables.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_stat_percpu" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Memory used for storing per-cpu kernel data structures.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_stat_sock" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Memory used in network transmission buffers.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_stat_vmalloc" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Memory used for vmap backed memory.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_stat_zswap" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Memory consumed by the zswap compression backend.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_stat_zswapped" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Amount of application memory swapped out to zswap.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_stat_file_mapped" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Cached filesystem data mapped with mmap().",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_stat_file_dirty" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Cached filesystem data that was modified but not yet written back to disk.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_stat_file_writeback" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Cached filesystem data that was modified and is currently being written back to disk.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_stat_swapcached" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Swap cached in memory. The swapcache is accounted against both memory and swap usage.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_stat_anon_thp" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Anonymous memory backed by transparent hugepages.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_stat_file_thp" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Cached filesystem data backed by transparent hugepages.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_stat_shmem_thp" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Shared memory backed by transparent hugepages.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_stat_slab_reclaimable" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Part of slab that might be reclaimed, such as dentries and inodes.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_stat_slab_unreclaimable" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Part of slab that cannot be reclaimed on memory pressure.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_stat_slab" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Memory used for storing in-kernel data structures.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_stat_workingset_refault_anon" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Number of refaults of previously evicted anonymous pages.",
+            labels: vec![],
+            rename: Some("memory_stat_workingset_refault_anon_total"),
+        },
+        unit: None,
+    },
+    "memory_stat_workingset_refault_file" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Number of refaults of previously evicted file pages.",
+            labels: vec![],
+            rename: Some("memory_stat_workingset_refault_file_total"),
+        },
+        unit: None,
+    },
+    "memory_stat_workingset_activate_anon" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Number of refaulted anonymous pages that were immediately activated.",
+            labels: vec![],
+            rename: Some("memory_stat_workingset_activate_anon_total"),
+        },
+        unit: None,
+    },
+    "memory_stat_workingset_activate_file" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Number of refaulted file pages that were immediately activated.",
+            labels: vec![],
+            rename: Some("memory_stat_workingset_activate_file_total"),
+        },
+        unit: None,
+    },
+    "memory_stat_workingset_restore_anon" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Number of restored anonymous pages which have been detected as an active workingset before they got reclaimed.",
+            labels: vec![],
+            rename: Some("memory_stat_workingset_restore_anon_total"),
+        },
+        unit: None,
+    },
+    "memory_stat_workingset_restore_file" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Number of restored file pages which have been detected as an active workingset before they got reclaimed.",
+            labels: vec![],
+            rename: Some("memory_stat_workingset_restore_file_total"),
+        },
+        unit: None,
+    },
+    "memory_stat_workingset_nodereclaim" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Number of times a shadow node has been reclaimed.",
+            labels: vec![],
+            rename: Some("memory_stat_workingset_nodereclaim_total"),
+        },
+        unit: None,
+    },
+    "memory_stat_pgrefill" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Number of scanned pages in an active LRU list.",
+            labels: vec![],
+            rename: Some("memory_stat_pgrefill_total"),
+        },
+        unit: None,
+    },
+    "memory_stat_pgscan" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Number of scanned pages in an inactive LRU list.",
+            labels: vec![],
+            rename: Some("memory_stat_pgscan_total"),
+        },
+        unit: None,
+    },
+    "memory_stat_pgsteal" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Number of reclaimed pages.",
+            labels: vec![],
+            rename: Some("memory_stat_pgsteal_total"),
+        },
+        unit: None,
+    },
+    "memory_stat_pgactivate" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Number of pages moved to the active LRU list.",
+            labels: vec![],
+            rename: Some("memory_stat_pgactivate_total"),
+        },
+        unit: None,
+    },
+    "memory_stat_pgdeactivate" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Number of pages moved to the inactive LRU list.",
+            labels: vec![],
+            rename: Some("memory_stat_pgdeactivate_total"),
+        },
+        unit: None,
+    },
+    "memory_stat_pglazyfree" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Number of pages postponed to be freed under memory pressure.",
+            labels: vec![],
+            rename: Some("memory_stat_pglazyfree_total"),
+        },
+        unit: None,
+    },
+    "memory_stat_pglazyfreed" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Number of reclaimed lazyfree pages.",
+            labels: vec![],
+            rename: Some("memory_stat_pglazyfreed_total"),
+        },
+        unit: None,
+    },
+    "memory_stat_thp_fault_alloc" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Number of transparent hugepages which were allocated to satisfy a page fault.",
+            labels: vec![],
+            rename: Some("memory_stat_thp_fault_alloc_total"),
+        },
+        unit: None,
+    },
+    "memory_stat_thp_collapse_alloc" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Number of transparent hugepages which were allocated to allow collapsing an existing range of pages.",
+            labels: vec![],
+            rename: Some("memory_stat_thp_collapse_alloc_total"),
+        },
+        unit: None,
+    },
+    "memory_events_low" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Number of times the cgroup was reclaimed due to high memory pressure even though its usage is under the low boundary.",
+            labels: vec![],
+            rename: Some("memory_events_low_total"),
+        },
+        unit: None,
+    },
+    "memory_events_high" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Number of times processes of the cgroup were throttled and routed to perform direct memory reclaim because the high memory boundary was exceeded.",
+            labels: vec![],
+            rename: Some("memory_events_high_total"),
+        },
+        unit: None,
+    },
+    "memory_events_max" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Number of times the cgroup's memory usage was about to go over the max boundary.",
+            labels: vec![],
+            rename: Some("memory_events_max_total"),
+        },
+        unit: None,
+    },
+    "memory_events_oom" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Number of times the cgroup's memory usage reached the limit and allocation was about to fail.",
+            labels: vec![],
+            rename: Some("memory_events_oom_total"),
+        },
+        unit: None,
+    },
+    "memory_events_oom_kill" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Number of processes belonging to this cgroup killed by any kind of OOM killer.",
+            labels: vec![],
+            rename: Some("memory_events_oom_kill_total"),
+        },
+        unit: None,
+    },
+    "memory_events_oom_group_kill" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Number of times a group OOM has occurred.",
+            labels: vec![],
+            rename: Some("memory_events_oom_group_kill_total"),
+        },
+        unit: None,
+    },
+    // DERIVED MEMORY METRICS (computed like cAdvisor on both cgroup versions)
+    "memory_working_set_bytes" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "The memory usage minus the inactive file cache, as reported by cAdvisor. Only exported with derivedMemory.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_rss" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Anonymous and swap cache memory in bytes, as reported by cAdvisor. Only exported with derivedMemory.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_cache" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Page cache memory in bytes, as reported by cAdvisor. Only exported with derivedMemory.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "memory_mapped_file" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Memory mapped files in bytes, as reported by cAdvisor. Only exported with derivedMemory.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
     },
     // CGROUP BLKIO METRICS
     // BlkIo struct fields
 
     // Field: io_merged: Vec<IoService>
-    // Nested IoService fields prefixed with "blkio_io_merged_"
-    "blkio_io_merged_major" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The major number of the device.",
-        labels: vec![],
-        rename: None,
-    },
-    "blkio_io_merged_minor" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The minor number of the device.",
-        labels: vec![],
-        rename: None,
-    },
-    "blkio_io_merged_read" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were read from the device.",
-        labels: vec![],
-        rename: Some("blkio_io_merged_read_total"),
-    },
-    "blkio_io_merged_write" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were written to the device.",
-        labels: vec![],
-        rename: Some("blkio_io_merged_write_total"),
-    },
-    "blkio_io_merged_sync" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were synchronously transferred.",
-        labels: vec![],
-        rename: Some("blkio_io_merged_sync_total"),
-    },
-    "blkio_io_merged_async" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were asynchronously transferred.",
-        labels: vec![],
-        rename: Some("blkio_io_merged_async_total"),
-    },
-    "blkio_io_merged_discard" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were discarded.",
-        labels: vec![],
-        rename: Some("blkio_io_merged_discard_total"),
-    },
-    "blkio_io_merged_total" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Total number of items transferred.",
-        labels: vec![],
-        rename: Some("blkio_io_merged_total"),
+    // Nested IoService fields, one series per device, prefixed with "blkio_io_merged_"
+    "blkio_io_merged_read" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were read from the device.",
+            labels: vec![],
+            rename: Some("blkio_io_merged_read_total"),
+        },
+        unit: None,
+    },
+    "blkio_io_merged_write" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were written to the device.",
+            labels: vec![],
+            rename: Some("blkio_io_merged_write_total"),
+        },
+        unit: None,
+    },
+    "blkio_io_merged_sync" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were synchronously transferred.",
+            labels: vec![],
+            rename: Some("blkio_io_merged_sync_total"),
+        },
+        unit: None,
+    },
+    "blkio_io_merged_async" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were asynchronously transferred.",
+            labels: vec![],
+            rename: Some("blkio_io_merged_async_total"),
+        },
+        unit: None,
+    },
+    "blkio_io_merged_discard" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were discarded.",
+            labels: vec![],
+            rename: Some("blkio_io_merged_discard_total"),
+        },
+        unit: None,
     },
     // Field: io_merged_total: u64
-    "blkio_io_merged_total" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Same as `io_merged`, but only reports the total number.",
-        labels: vec![],
-        rename: Some("blkio_io_merged_total"),
+    "blkio_io_merged_total" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Same as `io_merged`, but only reports the total number.",
+            labels: vec![],
+            rename: Some("blkio_io_merged_total"),
+        },
+        unit: None,
     },
     // Field: io_merged_recursive: Vec<IoService>
-    // Nested IoService fields prefixed with "blkio_io_merged_recursive_"
-    "blkio_io_merged_recursive_major" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The major number of the device.",
-        labels: vec![],
-        rename: None,
-    },
-    "blkio_io_merged_recursive_minor" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The minor number of the device.",
-        labels: vec![],
-        rename: None,
-    },
-    "blkio_io_merged_recursive_read" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were read from the device.",
-        labels: vec![],
-        rename: Some("blkio_io_merged_recursive_read_total"),
-    },
-    "blkio_io_merged_recursive_write" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were written to the device.",
-        labels: vec![],
-        rename: Some("blkio_io_merged_recursive_write_total"),
-    },
-    "blkio_io_merged_recursive_sync" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were synchronously transferred.",
-        labels: vec![],
-        rename: Some("blkio_io_merged_recursive_sync_total"),
-    },
-    "blkio_io_merged_recursive_async" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were asynchronously transferred.",
-        labels: vec![],
-        rename: Some("blkio_io_merged_recursive_async_total"),
-    },
-    "blkio_io_merged_recursive_discard" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were discarded.",
-        labels: vec![],
-        rename: Some("blkio_io_merged_recursive_discard_total"),
-    },
-    "blkio_io_merged_recursive_total" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Total number of items transferred.",
-        labels: vec![],
-        rename: Some("blkio_io_merged_recursive_total"),
+    // Nested IoService fields, one series per device, prefixed with "blkio_io_merged_recursive_"
+    "blkio_io_merged_recursive_read" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were read from the device.",
+            labels: vec![],
+            rename: Some("blkio_io_merged_recursive_read_total"),
+        },
+        unit: None,
+    },
+    "blkio_io_merged_recursive_write" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were written to the device.",
+            labels: vec![],
+            rename: Some("blkio_io_merged_recursive_write_total"),
+        },
+        unit: None,
+    },
+    "blkio_io_merged_recursive_sync" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were synchronously transferred.",
+            labels: vec![],
+            rename: Some("blkio_io_merged_recursive_sync_total"),
+        },
+        unit: None,
+    },
+    "blkio_io_merged_recursive_async" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were asynchronously transferred.",
+            labels: vec![],
+            rename: Some("blkio_io_merged_recursive_async_total"),
+        },
+        unit: None,
+    },
+    "blkio_io_merged_recursive_discard" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were discarded.",
+            labels: vec![],
+            rename: Some("blkio_io_merged_recursive_discard_total"),
+        },
+        unit: None,
     },
 // Field: io_merged_recursive_total: u64
 
-    "blkio_io_merged_recursive_total" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Same as `io_merged_recursive`, but only reports the total number.",
-        labels: vec![],
-        rename: Some("blkio_io_merged_recursive_total"),
+    "blkio_io_merged_recursive_total" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Same as `io_merged_recursive`, but only reports the total number.",
+            labels: vec![],
+            rename: Some("blkio_io_merged_recursive_total"),
+        },
+        unit: None,
     },
     // Field: io_queued: Vec<IoService>
-    // Nested IoService fields prefixed with "blkio_io_queued_"
-    "blkio_io_queued_major" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The major number of the device.",
-        labels: vec![],
-        rename: None,
-    },
-    "blkio_io_queued_minor" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The minor number of the device.",
-        labels: vec![],
-        rename: None,
-    },
-    "blkio_io_queued_read" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were read from the device.",
-        labels: vec![],
-        rename: Some("blkio_io_queued_read_total"),
-    },
-    "blkio_io_queued_write" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were written to the device.",
-        labels: vec![],
-        rename: Some("blkio_io_queued_write_total"),
-    },
-    "blkio_io_queued_sync" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were synchronously transferred.",
-        labels: vec![],
-        rename: Some("blkio_io_queued_sync_total"),
-    },
-    "blkio_io_queued_async" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were asynchronously transferred.",
-        labels: vec![],
-        rename: Some("blkio_io_queued_async_total"),
-    },
-    "blkio_io_queued_discard" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were discarded.",
-        labels: vec![],
-        rename: Some("blkio_io_queued_discard_total"),
-    },
-    "blkio_io_queued_total" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Total number of items transferred.",
-        labels: vec![],
-        rename: Some("blkio_io_queued_total"),
+    // Nested IoService fields, one series per device, prefixed with "blkio_io_queued_"
+    "blkio_io_queued_read" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were read from the device.",
+            labels: vec![],
+            rename: Some("blkio_io_queued_read_total"),
+        },
+        unit: None,
+    },
+    "blkio_io_queued_write" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were written to the device.",
+            labels: vec![],
+            rename: Some("blkio_io_queued_write_total"),
+        },
+        unit: None,
+    },
+    "blkio_io_queued_sync" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were synchronously transferred.",
+            labels: vec![],
+            rename: Some("blkio_io_queued_sync_total"),
+        },
+        unit: None,
+    },
+    "blkio_io_queued_async" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were asynchronously transferred.",
+            labels: vec![],
+            rename: Some("blkio_io_queued_async_total"),
+        },
+        unit: None,
+    },
+    "blkio_io_queued_discard" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were discarded.",
+            labels: vec![],
+            rename: Some("blkio_io_queued_discard_total"),
+        },
+        unit: None,
     },
     // Field: io_queued_total: u64
-    "blkio_io_queued_total" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Same as `io_queued`, but only reports the total number.",
-        labels: vec![],
-        rename: Some("blkio_io_queued_total"),
+    "blkio_io_queued_total" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Same as `io_queued`, but only reports the total number.",
+            labels: vec![],
+            rename: Some("blkio_io_queued_total"),
+        },
+        unit: None,
     },
     // Field: io_queued_recursive: Vec<IoService>
-    // Nested IoService fields prefixed with "blkio_io_queued_recursive_"
-    "blkio_io_queued_recursive_major" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The major number of the device.",
-        labels: vec![],
-        rename: None,
-    },
-    "blkio_io_queued_recursive_minor" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The minor number of the device.",
-        labels: vec![],
-        rename: None,
-    },
-    "blkio_io_queued_recursive_read" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were read from the device.",
-        labels: vec![],
-        rename: Some("blkio_io_queued_recursive_read_total"),
-    },
-    "blkio_io_queued_recursive_write" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were written to the device.",
-        labels: vec![],
-        rename: Some("blkio_io_queued_recursive_write_total"),
-    },
-    "blkio_io_queued_recursive_sync" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were synchronously transferred.",
-        labels: vec![],
-        rename: Some("blkio_io_queued_recursive_sync_total"),
-    },
-    "blkio_io_queued_recursive_async" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were asynchronously transferred.",
-        labels: vec![],
-        rename: Some("blkio_io_queued_recursive_async_total"),
-    },
-    "blkio_io_queued_recursive_discard" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were discarded.",
-        labels: vec![],
-        rename: Some("blkio_io_queued_recursive_discard_total"),
-    },
-    "blkio_io_queued_recursive_total" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Total number of items transferred.",
-        labels: vec![],
-        rename: Some("blkio_io_queued_recursive_total"),
+    // Nested IoService fields, one series per device, prefixed with "blkio_io_queued_recursive_"
+    "blkio_io_queued_recursive_read" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were read from the device.",
+            labels: vec![],
+            rename: Some("blkio_io_queued_recursive_read_total"),
+        },
+        unit: None,
+    },
+    "blkio_io_queued_recursive_write" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were written to the device.",
+            labels: vec![],
+            rename: Some("blkio_io_queued_recursive_write_total"),
+        },
+        unit: None,
+    },
+    "blkio_io_queued_recursive_sync" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were synchronously transferred.",
+            labels: vec![],
+            rename: Some("blkio_io_queued_recursive_sync_total"),
+        },
+        unit: None,
+    },
+    "blkio_io_queued_recursive_async" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were asynchronously transferred.",
+            labels: vec![],
+            rename: Some("blkio_io_queued_recursive_async_total"),
+        },
+        unit: None,
+    },
+    "blkio_io_queued_recursive_discard" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were discarded.",
+            labels: vec![],
+            rename: Some("blkio_io_queued_recursive_discard_total"),
+        },
+        unit: None,
     },
     // Field: io_queued_recursive_total: u64
-    "blkio_io_queued_recursive_total" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Same as `io_queued_recursive`, but only reports the total number.",
-        labels: vec![],
-        rename: Some("blkio_io_queued_recursive_total"),
+    "blkio_io_queued_recursive_total" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Same as `io_queued_recursive`, but only reports the total number.",
+            labels: vec![],
+            rename: Some("blkio_io_queued_recursive_total"),
+        },
+        unit: None,
     },
     // Field: io_service_bytes: Vec<IoService>
-    // Nested IoService fields prefixed with "blkio_io_service_bytes_"
-    "blkio_io_service_bytes_major" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The major number of the device.",
-        labels: vec![],
-        rename: None,
-    },
-    "blkio_io_service_bytes_minor" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The minor number of the device.",
-        labels: vec![],
-        rename: None,
-    },
-    "blkio_io_service_bytes_read" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were read from the device.",
-        labels: vec![],
-        rename: Some("blkio_io_service_bytes_read_total"),
-    },
-    "blkio_io_service_bytes_write" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were written to the device.",
-        labels: vec![],
-        rename: Some("blkio_io_service_bytes_write_total"),
-    },
-    "blkio_io_service_bytes_sync" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were synchronously transferred.",
-        labels: vec![],
-        rename: Some("blkio_io_service_bytes_sync_total"),
-    },
-    "blkio_io_service_bytes_async" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were asynchronously transferred.",
-        labels: vec![],
-        rename: Some("blkio_io_service_bytes_async_total"),
-    },
-    "blkio_io_service_bytes_discard" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were discarded.",
-        labels: vec![],
-        rename: Some("blkio_io_service_bytes_discard_total"),
-    },
-    "blkio_io_service_bytes_total" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Total number of items transferred.",
-        labels: vec![],
-        rename: Some("blkio_io_service_bytes_total"),
+    // Nested IoService fields, one series per device, prefixed with "blkio_io_service_bytes_"
+    "blkio_io_service_bytes_read" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were read from the device.",
+            labels: vec![],
+            rename: Some("blkio_io_service_bytes_read_total"),
+        },
+        unit: None,
+    },
+    "blkio_io_service_bytes_write" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were written to the device.",
+            labels: vec![],
+            rename: Some("blkio_io_service_bytes_write_total"),
+        },
+        unit: None,
+    },
+    "blkio_io_service_bytes_sync" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were synchronously transferred.",
+            labels: vec![],
+            rename: Some("blkio_io_service_bytes_sync_total"),
+        },
+        unit: None,
+    },
+    "blkio_io_service_bytes_async" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were asynchronously transferred.",
+            labels: vec![],
+            rename: Some("blkio_io_service_bytes_async_total"),
+        },
+        unit: None,
+    },
+    "blkio_io_service_bytes_discard" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were discarded.",
+            labels: vec![],
+            rename: Some("blkio_io_service_bytes_discard_total"),
+        },
+        unit: None,
     },
     // Field: io_service_bytes_total: u64
-    "blkio_io_service_bytes_total" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Same as `io_service_bytes`, but only reports the total number.",
-        labels: vec![],
-        rename: Some("blkio_io_service_bytes_total"),
+    "blkio_io_service_bytes_total" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Same as `io_service_bytes`, but only reports the total number.",
+            labels: vec![],
+            rename: Some("blkio_io_service_bytes_total"),
+        },
+        unit: None,
     },
     // Field: io_service_bytes_recursive: Vec<IoService>
-    // Nested IoService fields prefixed with "blkio_io_service_bytes_recursive_"
-    "blkio_io_service_bytes_recursive_major" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The major number of the device.",
-        labels: vec![],
-        rename: None,
-    },
-    "blkio_io_service_bytes_recursive_minor" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The minor number of the device.",
-        labels: vec![],
-        rename: None,
-    },
-    "blkio_io_service_bytes_recursive_read" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were read from the device.",
-        labels: vec![],
-        rename: Some("blkio_io_service_bytes_recursive_read_total"),
-    },
-    "blkio_io_service_bytes_recursive_write" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were written to the device.",
-        labels: vec![],
-        rename: Some("blkio_io_service_bytes_recursive_write_total"),
-    },
-    "blkio_io_service_bytes_recursive_sync" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were synchronously transferred.",
-        labels: vec![],
-        rename: Some("blkio_io_service_bytes_recursive_sync_total"),
-    },
-    "blkio_io_service_bytes_recursive_async" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were asynchronously transferred.",
-        labels: vec![],
-        rename: Some("blkio_io_service_bytes_recursive_async_total"),
-    },
-    "blkio_io_service_bytes_recursive_discard" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were discarded.",
-        labels: vec![],
-        rename: Some("blkio_io_service_bytes_recursive_discard_total"),
-    },
-    "blkio_io_service_bytes_recursive_total" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Total number of items transferred.",
-        labels: vec![],
-        rename: Some("blkio_io_service_bytes_recursive_total"),
+    // Nested IoService fields, one series per device, prefixed with "blkio_io_service_bytes_recursive_"
+    "blkio_io_service_bytes_recursive_read" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were read from the device.",
+            labels: vec![],
+            rename: Some("blkio_io_service_bytes_recursive_read_total"),
+        },
+        unit: None,
+    },
+    "blkio_io_service_bytes_recursive_write" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were written to the device.",
+            labels: vec![],
+            rename: Some("blkio_io_service_bytes_recursive_write_total"),
+        },
+        unit: None,
+    },
+    "blkio_io_service_bytes_recursive_sync" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were synchronously transferred.",
+            labels: vec![],
+            rename: Some("blkio_io_service_bytes_recursive_sync_total"),
+        },
+        unit: None,
+    },
+    "blkio_io_service_bytes_recursive_async" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were asynchronously transferred.",
+            labels: vec![],
+            rename: Some("blkio_io_service_bytes_recursive_async_total"),
+        },
+        unit: None,
+    },
+    "blkio_io_service_bytes_recursive_discard" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were discarded.",
+            labels: vec![],
+            rename: Some("blkio_io_service_bytes_recursive_discard_total"),
+        },
+        unit: None,
     },
     // Field: io_service_bytes_recursive_total: u64
-    "blkio_io_service_bytes_recursive_total" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Total amount of bytes transferred between the tasks and block devices, including descendant control groups.",
-        labels: vec![],
-        rename: Some("blkio_io_service_bytes_recursive_total"),
+    "blkio_io_service_bytes_recursive_total" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Total amount of bytes transferred between the tasks and block devices, including descendant control groups.",
+            labels: vec![],
+            rename: Some("blkio_io_service_bytes_recursive_total"),
+        },
+        unit: None,
     },
     // Field: io_serviced: Vec<IoService>
-    // Nested IoService fields prefixed with "blkio_io_serviced_"
-    "blkio_io_serviced_major" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The major number of the device.",
-        labels: vec![],
-        rename: None,
-    },
-    "blkio_io_serviced_minor" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The minor number of the device.",
-        labels: vec![],
-        rename: None,
-    },
-    "blkio_io_serviced_read" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were read from the device.",
-        labels: vec![],
-        rename: Some("blkio_io_serviced_read_total"),
-    },
-    "blkio_io_serviced_write" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were written to the device.",
-        labels: vec![],
-        rename: Some("blkio_io_serviced_write_total"),
-    },
-    "blkio_io_serviced_sync" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were synchronously transferred.",
-        labels: vec![],
-        rename: Some("blkio_io_serviced_sync_total"),
-    },
-    "blkio_io_serviced_async" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were asynchronously transferred.",
-        labels: vec![],
-        rename: Some("blkio_io_serviced_async_total"),
-    },
-    "blkio_io_serviced_discard" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were discarded.",
-        labels: vec![],
-        rename: Some("blkio_io_serviced_discard_total"),
-    },
-    "blkio_io_serviced_total" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Total number of items transferred.",
-        labels: vec![],
-        rename: Some("blkio_io_serviced_total"),
+    // Nested IoService fields, one series per device, prefixed with "blkio_io_serviced_"
+    "blkio_io_serviced_read" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were read from the device.",
+            labels: vec![],
+            rename: Some("blkio_io_serviced_read_total"),
+        },
+        unit: None,
+    },
+    "blkio_io_serviced_write" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were written to the device.",
+            labels: vec![],
+            rename: Some("blkio_io_serviced_write_total"),
+        },
+        unit: None,
+    },
+    "blkio_io_serviced_sync" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were synchronously transferred.",
+            labels: vec![],
+            rename: Some("blkio_io_serviced_sync_total"),
+        },
+        unit: None,
+    },
+    "blkio_io_serviced_async" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were asynchronously transferred.",
+            labels: vec![],
+            rename: Some("blkio_io_serviced_async_total"),
+        },
+        unit: None,
+    },
+    "blkio_io_serviced_discard" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were discarded.",
+            labels: vec![],
+            rename: Some("blkio_io_serviced_discard_total"),
+        },
+        unit: None,
     },
     // Field: io_serviced_total: u64
-    "blkio_io_serviced_total" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "The total number of I/O operations performed on the devices as seen by the throttling policy.",
-        labels: vec![],
-        rename: Some("blkio_io_serviced_total"),
+    "blkio_io_serviced_total" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "The total number of I/O operations performed on the devices as seen by the throttling policy.",
+            labels: vec![],
+            rename: Some("blkio_io_serviced_total"),
+        },
+        unit: None,
     },
     // Field: io_serviced_recursive: Vec<IoService>
-    // Nested IoService fields prefixed with "blkio_io_serviced_recursive_"
-    "blkio_io_serviced_recursive_major" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The major number of the device.",
-        labels: vec![],
-        rename: None,
-    },
-    "blkio_io_serviced_recursive_minor" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The minor number of the device.",
-        labels: vec![],
-        rename: None,
-    },
-    "blkio_io_serviced_recursive_read" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were read from the device.",
-        labels: vec![],
-        rename: Some("blkio_io_serviced_recursive_read_total"),
-    },
-    "blkio_io_serviced_recursive_write" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were written to the device.",
-        labels: vec![],
-        rename: Some("blkio_io_serviced_recursive_write_total"),
-    },
-    "blkio_io_serviced_recursive_sync" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were synchronously transferred.",
-        labels: vec![],
-        rename: Some("blkio_io_serviced_recursive_sync_total"),
-    },
-    "blkio_io_serviced_recursive_async" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were asynchronously transferred.",
-        labels: vec![],
-        rename: Some("blkio_io_serviced_recursive_async_total"),
-    },
-    "blkio_io_serviced_recursive_discard" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were discarded.",
-        labels: vec![],
-        rename: Some("blkio_io_serviced_recursive_discard_total"),
-    },
-    "blkio_io_serviced_recursive_total" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Same as `io_serviced`, but contains all descendant control groups and only the total amount.",
-        labels: vec![],
-        rename: Some("blkio_io_serviced_recursive_total"),
+    // Nested IoService fields, one series per device, prefixed with "blkio_io_serviced_recursive_"
+    "blkio_io_serviced_recursive_read" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were read from the device.",
+            labels: vec![],
+            rename: Some("blkio_io_serviced_recursive_read_total"),
+        },
+        unit: None,
+    },
+    "blkio_io_serviced_recursive_write" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were written to the device.",
+            labels: vec![],
+            rename: Some("blkio_io_serviced_recursive_write_total"),
+        },
+        unit: None,
+    },
+    "blkio_io_serviced_recursive_sync" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were synchronously transferred.",
+            labels: vec![],
+            rename: Some("blkio_io_serviced_recursive_sync_total"),
+        },
+        unit: None,
+    },
+    "blkio_io_serviced_recursive_async" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were asynchronously transferred.",
+            labels: vec![],
+            rename: Some("blkio_io_serviced_recursive_async_total"),
+        },
+        unit: None,
+    },
+    "blkio_io_serviced_recursive_discard" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were discarded.",
+            labels: vec![],
+            rename: Some("blkio_io_serviced_recursive_discard_total"),
+        },
+        unit: None,
     },
     // Field: io_serviced_recursive_total: u64
-    "blkio_io_serviced_recursive_total" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Same as `io_serviced_recursive`, but contains all descendant control groups and only the total amount.",
-        labels: vec![],
-        rename: Some("blkio_io_serviced_recursive_total"),
+    "blkio_io_serviced_recursive_total" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Same as `io_serviced_recursive`, but contains all descendant control groups and only the total amount.",
+            labels: vec![],
+            rename: Some("blkio_io_serviced_recursive_total"),
+        },
+        unit: None,
     },
     // Field: io_service_time: Vec<IoService>
-    // Nested IoService fields prefixed with "blkio_io_service_time_"
-    "blkio_io_service_time_major" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The major number of the device.",
-        labels: vec![],
-        rename: None,
-    },
-    "blkio_io_service_time_minor" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The minor number of the device.",
-        labels: vec![],
-        rename: None,
-    },
-    "blkio_io_service_time_read" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were read from the device.",
-        labels: vec![],
-        rename: Some("blkio_io_service_time_read_total"),
-    },
-    "blkio_io_service_time_write" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were written to the device.",
-        labels: vec![],
-        rename: Some("blkio_io_service_time_write_total"),
-    },
-    "blkio_io_service_time_sync" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were synchronously transferred.",
-        labels: vec![],
-        rename: Some("blkio_io_service_time_sync_total"),
-    },
-    "blkio_io_service_time_async" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were asynchronously transferred.",
-        labels: vec![],
-        rename: Some("blkio_io_service_time_async_total"),
-    },
-    "blkio_io_service_time_discard" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were discarded.",
-        labels: vec![],
-        rename: Some("blkio_io_service_time_discard_total"),
-    },
-    "blkio_io_service_time_total" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Total time spent between dispatch and completion for I/O requests.",
-        labels: vec![],
-        rename: Some("blkio_io_service_time_total"),
+    // Nested IoService fields, one series per device, prefixed with "blkio_io_service_time_"
+    "blkio_io_service_time_read" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were read from the device.",
+            labels: vec![],
+            rename: Some("blkio_io_service_time_read_total"),
+        },
+        unit: Some(Unit::Nanoseconds),
+    },
+    "blkio_io_service_time_write" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were written to the device.",
+            labels: vec![],
+            rename: Some("blkio_io_service_time_write_total"),
+        },
+        unit: Some(Unit::Nanoseconds),
+    },
+    "blkio_io_service_time_sync" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were synchronously transferred.",
+            labels: vec![],
+            rename: Some("blkio_io_service_time_sync_total"),
+        },
+        unit: Some(Unit::Nanoseconds),
+    },
+    "blkio_io_service_time_async" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were asynchronously transferred.",
+            labels: vec![],
+            rename: Some("blkio_io_service_time_async_total"),
+        },
+        unit: Some(Unit::Nanoseconds),
+    },
+    "blkio_io_service_time_discard" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were discarded.",
+            labels: vec![],
+            rename: Some("blkio_io_service_time_discard_total"),
+        },
+        unit: Some(Unit::Nanoseconds),
     },
     // Field: io_service_time_total: u64
-    "blkio_io_service_time_total" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Same as `io_service_time`, but reports only the total amount.",
-        labels: vec![],
-        rename: Some("blkio_io_service_time_total"),
+    "blkio_io_service_time_total" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Same as `io_service_time`, but reports only the total amount.",
+            labels: vec![],
+            rename: Some("blkio_io_service_time_total"),
+        },
+        unit: Some(Unit::Nanoseconds),
     },
     // Field: io_service_time_recursive: Vec<IoService>
-    // Nested IoService fields prefixed with "blkio_io_service_time_recursive_"
-    "blkio_io_service_time_recursive_major" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The major number of the device.",
-        labels: vec![],
-        rename: None,
-    },
-    "blkio_io_service_time_recursive_minor" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The minor number of the device.",
-        labels: vec![],
-        rename: None,
-    },
-    "blkio_io_service_time_recursive_read" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were read from the device.",
-        labels: vec![],
-        rename: Some("blkio_io_service_time_recursive_read_total"),
-    },
-    "blkio_io_service_time_recursive_write" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were written to the device.",
-        labels: vec![],
-        rename: Some("blkio_io_service_time_recursive_write_total"),
-    },
-    "blkio_io_service_time_recursive_sync" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were synchronously transferred.",
-        labels: vec![],
-        rename: Some("blkio_io_service_time_recursive_sync_total"),
-    },
-    "blkio_io_service_time_recursive_async" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were asynchronously transferred.",
-        labels: vec![],
-        rename: Some("blkio_io_service_time_recursive_async_total"),
-    },
-    "blkio_io_service_time_recursive_discard" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were discarded.",
-        labels: vec![],
-        rename: Some("blkio_io_service_time_recursive_discard_total"),
-    },
-    "blkio_io_service_time_recursive_total" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Same as `io_service_time_recursive`, but reports only the total amount.",
-        labels: vec![],
-        rename: Some("blkio_io_service_time_recursive_total"),
+    // Nested IoService fields, one series per device, prefixed with "blkio_io_service_time_recursive_"
+    "blkio_io_service_time_recursive_read" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were read from the device.",
+            labels: vec![],
+            rename: Some("blkio_io_service_time_recursive_read_total"),
+        },
+        unit: Some(Unit::Nanoseconds),
+    },
+    "blkio_io_service_time_recursive_write" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were written to the device.",
+            labels: vec![],
+            rename: Some("blkio_io_service_time_recursive_write_total"),
+        },
+        unit: Some(Unit::Nanoseconds),
+    },
+    "blkio_io_service_time_recursive_sync" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were synchronously transferred.",
+            labels: vec![],
+            rename: Some("blkio_io_service_time_recursive_sync_total"),
+        },
+        unit: Some(Unit::Nanoseconds),
+    },
+    "blkio_io_service_time_recursive_async" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were asynchronously transferred.",
+            labels: vec![],
+            rename: Some("blkio_io_service_time_recursive_async_total"),
+        },
+        unit: Some(Unit::Nanoseconds),
+    },
+    "blkio_io_service_time_recursive_discard" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were discarded.",
+            labels: vec![],
+            rename: Some("blkio_io_service_time_recursive_discard_total"),
+        },
+        unit: Some(Unit::Nanoseconds),
     },
     // Field: io_service_time_recursive_total: u64
-    "blkio_io_service_time_recursive_total" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Same as `io_service_time_recursive`, but reports only the total amount.",
-        labels: vec![],
-        rename: Some("blkio_io_service_time_recursive_total"),
+    "blkio_io_service_time_recursive_total" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Same as `io_service_time_recursive`, but reports only the total amount.",
+            labels: vec![],
+            rename: Some("blkio_io_service_time_recursive_total"),
+        },
+        unit: Some(Unit::Nanoseconds),
     },
     // Field: io_wait_time: Vec<IoService>
-    // Nested IoService fields prefixed with "blkio_io_wait_time_"
-    "blkio_io_wait_time_major" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The major number of the device.",
-        labels: vec![],
-        rename: None,
-    },
-    "blkio_io_wait_time_minor" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The minor number of the device.",
-        labels: vec![],
-        rename: None,
-    },
-    "blkio_io_wait_time_read" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were read from the device.",
-        labels: vec![],
-        rename: Some("blkio_io_wait_time_read_total"),
-    },
-    "blkio_io_wait_time_write" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were written to the device.",
-        labels: vec![],
-        rename: Some("blkio_io_wait_time_write_total"),
-    },
-    "blkio_io_wait_time_sync" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were synchronously transferred.",
-        labels: vec![],
-        rename: Some("blkio_io_wait_time_sync_total"),
-    },
-    "blkio_io_wait_time_async" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were asynchronously transferred.",
-        labels: vec![],
-        rename: Some("blkio_io_wait_time_async_total"),
-    },
-    "blkio_io_wait_time_discard" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were discarded.",
-        labels: vec![],
-        rename: Some("blkio_io_wait_time_discard_total"),
-    },
-    "blkio_io_wait_time_total" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Total number of items transferred.",
-        labels: vec![],
-        rename: Some("blkio_io_wait_time_total"),
+    // Nested IoService fields, one series per device, prefixed with "blkio_io_wait_time_"
+    "blkio_io_wait_time_read" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were read from the device.",
+            labels: vec![],
+            rename: Some("blkio_io_wait_time_read_total"),
+        },
+        unit: Some(Unit::Nanoseconds),
+    },
+    "blkio_io_wait_time_write" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were written to the device.",
+            labels: vec![],
+            rename: Some("blkio_io_wait_time_write_total"),
+        },
+        unit: Some(Unit::Nanoseconds),
+    },
+    "blkio_io_wait_time_sync" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were synchronously transferred.",
+            labels: vec![],
+            rename: Some("blkio_io_wait_time_sync_total"),
+        },
+        unit: Some(Unit::Nanoseconds),
+    },
+    "blkio_io_wait_time_async" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were asynchronously transferred.",
+            labels: vec![],
+            rename: Some("blkio_io_wait_time_async_total"),
+        },
+        unit: Some(Unit::Nanoseconds),
+    },
+    "blkio_io_wait_time_discard" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were discarded.",
+            labels: vec![],
+            rename: Some("blkio_io_wait_time_discard_total"),
+        },
+        unit: Some(Unit::Nanoseconds),
     },
     // Field: io_wait_time_total: u64
-    "blkio_io_wait_time_total" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Same as `io_wait_time`, but only reports the total amount.",
-        labels: vec![],
-        rename: Some("blkio_io_wait_time_total"),
+    "blkio_io_wait_time_total" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Same as `io_wait_time`, but only reports the total amount.",
+            labels: vec![],
+            rename: Some("blkio_io_wait_time_total"),
+        },
+        unit: Some(Unit::Nanoseconds),
     },
     // Field: io_wait_time_recursive: Vec<IoService)
-    // Nested IoService fields prefixed with "blkio_io_wait_time_recursive_"
-    "blkio_io_wait_time_recursive_major" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The major number of the device.",
-        labels: vec![],
-        rename: None,
-    },
-    "blkio_io_wait_time_recursive_minor" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The minor number of the device.",
-        labels: vec![],
-        rename: None,
-    },
-    "blkio_io_wait_time_recursive_read" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were read from the device.",
-        labels: vec![],
-        rename: Some("blkio_io_wait_time_recursive_read_total"),
-    },
-    "blkio_io_wait_time_recursive_write" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were written to the device.",
-        labels: vec![],
-        rename: Some("blkio_io_wait_time_recursive_write_total"),
-    },
-    "blkio_io_wait_time_recursive_sync" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were synchronously transferred.",
-        labels: vec![],
-        rename: Some("blkio_io_wait_time_recursive_sync_total"),
-    },
-    "blkio_io_wait_time_recursive_async" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were asynchronously transferred.",
-        labels: vec![],
-        rename: Some("blkio_io_wait_time_recursive_async_total"),
-    },
-    "blkio_io_wait_time_recursive_discard" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many items were discarded.",
-        labels: vec![],
-        rename: Some("blkio_io_wait_time_recursive_discard_total"),
-    },
-    "blkio_io_wait_time_recursive_total" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Same as `io_wait_time_recursive`, but only reports the total amount.",
-        labels: vec![],
-        rename: Some("blkio_io_wait_time_recursive_total"),
+    // Nested IoService fields, one series per device, prefixed with "blkio_io_wait_time_recursive_"
+    "blkio_io_wait_time_recursive_read" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were read from the device.",
+            labels: vec![],
+            rename: Some("blkio_io_wait_time_recursive_read_total"),
+        },
+        unit: Some(Unit::Nanoseconds),
+    },
+    "blkio_io_wait_time_recursive_write" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were written to the device.",
+            labels: vec![],
+            rename: Some("blkio_io_wait_time_recursive_write_total"),
+        },
+        unit: Some(Unit::Nanoseconds),
+    },
+    "blkio_io_wait_time_recursive_sync" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were synchronously transferred.",
+            labels: vec![],
+            rename: Some("blkio_io_wait_time_recursive_sync_total"),
+        },
+        unit: Some(Unit::Nanoseconds),
+    },
+    "blkio_io_wait_time_recursive_async" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were asynchronously transferred.",
+            labels: vec![],
+            rename: Some("blkio_io_wait_time_recursive_async_total"),
+        },
+        unit: Some(Unit::Nanoseconds),
+    },
+    "blkio_io_wait_time_recursive_discard" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many items were discarded.",
+            labels: vec![],
+            rename: Some("blkio_io_wait_time_recursive_discard_total"),
+        },
+        unit: Some(Unit::Nanoseconds),
     },
     // Field: io_wait_time_recursive_total: u64
-    "blkio_io_wait_time_recursive_total" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Same as `io_wait_time_recursive`, but only reports the total amount.",
-        labels: vec![],
-        rename: Some("blkio_io_wait_time_recursive_total"),
+    "blkio_io_wait_time_recursive_total" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Same as `io_wait_time_recursive`, but only reports the total amount.",
+            labels: vec![],
+            rename: Some("blkio_io_wait_time_recursive_total"),
+        },
+        unit: Some(Unit::Nanoseconds),
     },
     // Field: leaf_weight: u64
-    "blkio_leaf_weight" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "How much weight the control group's tasks have when competing against descendant control groups.",
-        labels: vec![],
-        rename: None,
+    "blkio_leaf_weight" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "How much weight the control group's tasks have when competing against descendant control groups.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
     },
     // Field: leaf_weight_device: Vec<BlkIoData>
-    // Nested BlkIoData fields prefixed with "blkio_leaf_weight_device_"
-    "blkio_leaf_weight_device_major" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The major number of the device.",
-        labels: vec![],
-        rename: None,
-    },
-    "blkio_leaf_weight_device_minor" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The minor number of the device.",
-        labels: vec![],
-        rename: None,
-    },
-    "blkio_leaf_weight_device_data" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The data associated with the device.",
-        labels: vec![],
-        rename: None,
+    // Nested BlkIoData fields, one series per device, prefixed with "blkio_leaf_weight_device_"
+    "blkio_leaf_weight_device" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "The data associated with the device.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
     },
     // Field: sectors: Vec<BlkIoData>
-    // Nested BlkIoData fields prefixed with "blkio_sectors_"
-    "blkio_sectors_major" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The major number of the device.",
-        labels: vec![],
-        rename: None,
-    },
-    "blkio_sectors_minor" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The minor number of the device.",
-        labels: vec![],
-        rename: None,
-    },
-    "blkio_sectors_data" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The sector count transferred.",
-        labels: vec![],
-        rename: None,
+    // Nested BlkIoData fields, one series per device, prefixed with "blkio_sectors_"
+    "blkio_sectors" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "The sector count transferred.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
     },
     // Field: sectors_recursive: Vec<BlkIoData>
-    // Nested BlkIoData fields prefixed with "blkio_sectors_recursive_"
-    "blkio_sectors_recursive_major" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The major number of the device.",
-        labels: vec![],
-        rename: None,
-    },
-    "blkio_sectors_recursive_minor" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The minor number of the device.",
-        labels: vec![],
-        rename: None,
-    },
-    "blkio_sectors_recursive_data" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The sector count transferred, including descendant control groups.",
-        labels: vec![],
-        rename: None,
+    // Nested BlkIoData fields, one series per device, prefixed with "blkio_sectors_recursive_"
+    "blkio_sectors_recursive" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "The sector count transferred, including descendant control groups.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
     },
     // Field: throttle: BlkIoThrottle
-    // Nested BlkIoThrottle fields prefixed with "blkio_throttle_"
+    // Nested BlkIoThrottle fields, one series per device, prefixed with "blkio_throttle_"
     // Field: throttle.io_service_bytes: Vec<IoService>
-    "blkio_throttle_io_service_bytes_major" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The major number of the device (throttle io_service_bytes).",
-        labels: vec![],
-        rename: None,
-    },
-    "blkio_throttle_io_service_bytes_minor" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The minor number of the device (throttle io_service_bytes).",
-        labels: vec![],
-        rename: None,
-    },
-    "blkio_throttle_io_service_bytes_read" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Bytes transferred (read) as seen by the throttle policy.",
-        labels: vec![],
-        rename: Some("blkio_throttle_io_service_bytes_read_total"),
-    },
-    "blkio_throttle_io_service_bytes_write" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Bytes transferred (write) as seen by the throttle policy.",
-        labels: vec![],
-        rename: Some("blkio_throttle_io_service_bytes_write_total"),
-    },
-    "blkio_throttle_io_service_bytes_sync" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Synchronous bytes transferred as seen by the throttle policy.",
-        labels: vec![],
-        rename: Some("blkio_throttle_io_service_bytes_sync_total"),
-    },
-    "blkio_throttle_io_service_bytes_async" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Asynchronous bytes transferred as seen by the throttle policy.",
-        labels: vec![],
-        rename: Some("blkio_throttle_io_service_bytes_async_total"),
-    },
-    "blkio_throttle_io_service_bytes_discard" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Discarded bytes as seen by the throttle policy.",
-        labels: vec![],
-        rename: Some("blkio_throttle_io_service_bytes_discard_total"),
-    },
-    "blkio_throttle_io_service_bytes_total" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Total bytes transferred as seen by the throttle policy.",
-        labels: vec![],
-        rename: Some("blkio_throttle_io_service_bytes_total"),
+    "blkio_throttle_io_service_bytes_read" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Bytes transferred (read) as seen by the throttle policy.",
+            labels: vec![],
+            rename: Some("blkio_throttle_io_service_bytes_read_total"),
+        },
+        unit: None,
+    },
+    "blkio_throttle_io_service_bytes_write" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Bytes transferred (write) as seen by the throttle policy.",
+            labels: vec![],
+            rename: Some("blkio_throttle_io_service_bytes_write_total"),
+        },
+        unit: None,
+    },
+    "blkio_throttle_io_service_bytes_sync" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Synchronous bytes transferred as seen by the throttle policy.",
+            labels: vec![],
+            rename: Some("blkio_throttle_io_service_bytes_sync_total"),
+        },
+        unit: None,
+    },
+    "blkio_throttle_io_service_bytes_async" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Asynchronous bytes transferred as seen by the throttle policy.",
+            labels: vec![],
+            rename: Some("blkio_throttle_io_service_bytes_async_total"),
+        },
+        unit: None,
+    },
+    "blkio_throttle_io_service_bytes_discard" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Discarded bytes as seen by the throttle policy.",
+            labels: vec![],
+            rename: Some("blkio_throttle_io_service_bytes_discard_total"),
+        },
+        unit: None,
     },
     // Field: throttle.io_service_bytes_total: u64
-    "blkio_throttle_io_service_bytes_total" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Total amount of bytes transferred as seen by the throttle policy.",
-        labels: vec![],
-        rename: Some("blkio_throttle_io_service_bytes_total"),
+    "blkio_throttle_io_service_bytes_total" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Total amount of bytes transferred as seen by the throttle policy.",
+            labels: vec![],
+            rename: Some("blkio_throttle_io_service_bytes_total"),
+        },
+        unit: None,
     },
     // Field: throttle.io_service_bytes_recursive: Vec<IoService>
-    "blkio_throttle_io_service_bytes_recursive_major" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The major number of the device (throttle io_service_bytes_recursive).",
-        labels: vec![],
-        rename: None,
-    },
-    "blkio_throttle_io_service_bytes_recursive_minor" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The minor number of the device (throttle io_service_bytes_recursive).",
-        labels: vec![],
-        rename: None,
-    },
-    "blkio_throttle_io_service_bytes_recursive_read" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Bytes transferred (read) recursively as seen by the throttle policy.",
-        labels: vec![],
-        rename: Some("blkio_throttle_io_service_bytes_recursive_read_total"),
-    },
-    "blkio_throttle_io_service_bytes_recursive_write" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Bytes transferred (write) recursively as seen by the throttle policy.",
-        labels: vec![],
-        rename: Some("blkio_throttle_io_service_bytes_recursive_write_total"),
-    },
-    "blkio_throttle_io_service_bytes_recursive_sync" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Synchronous bytes transferred recursively as seen by the throttle policy.",
-        labels: vec![],
-        rename: Some("blkio_throttle_io_service_bytes_recursive_sync_total"),
-    },
-    "blkio_throttle_io_service_bytes_recursive_async" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Asynchronous bytes transferred recursively as seen by the throttle policy.",
-        labels: vec![],
-        rename: Some("blkio_throttle_io_service_bytes_recursive_async_total"),
-    },
-    "blkio_throttle_io_service_bytes_recursive_discard" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Discarded bytes recursively as seen by the throttle policy.",
-        labels: vec![],
-        rename: Some("blkio_throttle_io_service_bytes_recursive_discard_total"),
-    },
-    "blkio_throttle_io_service_bytes_recursive_total" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Total recursive bytes transferred as seen by the throttle policy.",
-        labels: vec![],
-        rename: Some("blkio_throttle_io_service_bytes_recursive_total"),
+    "blkio_throttle_io_service_bytes_recursive_read" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Bytes transferred (read) recursively as seen by the throttle policy.",
+            labels: vec![],
+            rename: Some("blkio_throttle_io_service_bytes_recursive_read_total"),
+        },
+        unit: None,
+    },
+    "blkio_throttle_io_service_bytes_recursive_write" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Bytes transferred (write) recursively as seen by the throttle policy.",
+            labels: vec![],
+            rename: Some("blkio_throttle_io_service_bytes_recursive_write_total"),
+        },
+        unit: None,
+    },
+    "blkio_throttle_io_service_bytes_recursive_sync" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Synchronous bytes transferred recursively as seen by the throttle policy.",
+            labels: vec![],
+            rename: Some("blkio_throttle_io_service_bytes_recursive_sync_total"),
+        },
+        unit: None,
+    },
+    "blkio_throttle_io_service_bytes_recursive_async" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Asynchronous bytes transferred recursively as seen by the throttle policy.",
+            labels: vec![],
+            rename: Some("blkio_throttle_io_service_bytes_recursive_async_total"),
+        },
+        unit: None,
+    },
+    "blkio_throttle_io_service_bytes_recursive_discard" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Discarded bytes recursively as seen by the throttle policy.",
+            labels: vec![],
+            rename: Some("blkio_throttle_io_service_bytes_recursive_discard_total"),
+        },
+        unit: None,
     },
     // Field: throttle.io_service_bytes_recursive_total: u64
-    "blkio_throttle_io_service_bytes_recursive_total" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Total amount of bytes transferred recursively as seen by the throttle policy.",
-        labels: vec![],
-        rename: Some("blkio_throttle_io_service_bytes_recursive_total"),
+    "blkio_throttle_io_service_bytes_recursive_total" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Total amount of bytes transferred recursively as seen by the throttle policy.",
+            labels: vec![],
+            rename: Some("blkio_throttle_io_service_bytes_recursive_total"),
+        },
+        unit: None,
     },
     // Field: throttle.io_serviced: Vec<IoService>
-    "blkio_throttle_io_serviced_major" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The major number of the device (throttle io_serviced).",
-        labels: vec![],
-        rename: None,
-    },
-    "blkio_throttle_io_serviced_minor" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The minor number of the device (throttle io_serviced).",
-        labels: vec![],
-        rename: None,
-    },
-    "blkio_throttle_io_serviced_read" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Number of I/O operations read as seen by the throttle policy.",
-        labels: vec![],
-        rename: Some("blkio_throttle_io_serviced_read_total"),
-    },
-    "blkio_throttle_io_serviced_write" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Number of I/O operations written as seen by the throttle policy.",
-        labels: vec![],
-        rename: Some("blkio_throttle_io_serviced_write_total"),
-    },
-    "blkio_throttle_io_serviced_sync" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Synchronous I/O operations as seen by the throttle policy.",
-        labels: vec![],
-        rename: Some("blkio_throttle_io_serviced_sync_total"),
-    },
-    "blkio_throttle_io_serviced_async" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Asynchronous I/O operations as seen by the throttle policy.",
-        labels: vec![],
-        rename: Some("blkio_throttle_io_serviced_async_total"),
-    },
-    "blkio_throttle_io_serviced_discard" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Discarded I/O operations as seen by the throttle policy.",
-        labels: vec![],
-        rename: Some("blkio_throttle_io_serviced_discard_total"),
-    },
-    "blkio_throttle_io_serviced_total" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Total I/O operations as seen by the throttle policy.",
-        labels: vec![],
-        rename: Some("blkio_throttle_io_serviced_total"),
+    "blkio_throttle_io_serviced_read" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Number of I/O operations read as seen by the throttle policy.",
+            labels: vec![],
+            rename: Some("blkio_throttle_io_serviced_read_total"),
+        },
+        unit: None,
+    },
+    "blkio_throttle_io_serviced_write" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Number of I/O operations written as seen by the throttle policy.",
+            labels: vec![],
+            rename: Some("blkio_throttle_io_serviced_write_total"),
+        },
+        unit: None,
+    },
+    "blkio_throttle_io_serviced_sync" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Synchronous I/O operations as seen by the throttle policy.",
+            labels: vec![],
+            rename: Some("blkio_throttle_io_serviced_sync_total"),
+        },
+        unit: None,
+    },
+    "blkio_throttle_io_serviced_async" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Asynchronous I/O operations as seen by the throttle policy.",
+            labels: vec![],
+            rename: Some("blkio_throttle_io_serviced_async_total"),
+        },
+        unit: None,
+    },
+    "blkio_throttle_io_serviced_discard" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Discarded I/O operations as seen by the throttle policy.",
+            labels: vec![],
+            rename: Some("blkio_throttle_io_serviced_discard_total"),
+        },
+        unit: None,
     },
     // Field: throttle.io_serviced_total: u64
-    "blkio_throttle_io_serviced_total" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "The total number of I/O operations performed as seen by the throttle policy.",
-        labels: vec![],
-        rename: Some("blkio_throttle_io_serviced_total"),
+    "blkio_throttle_io_serviced_total" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "The total number of I/O operations performed as seen by the throttle policy.",
+            labels: vec![],
+            rename: Some("blkio_throttle_io_serviced_total"),
+        },
+        unit: None,
     },
     // Field: throttle.io_serviced_recursive: Vec<IoService>
-    "blkio_throttle_io_serviced_recursive_major" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The major number of the device (throttle io_serviced_recursive).",
-        labels: vec![],
-        rename: None,
-    },
-    "blkio_throttle_io_serviced_recursive_minor" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The minor number of the device (throttle io_serviced_recursive).",
-        labels: vec![],
-        rename: None,
-    },
-    "blkio_throttle_io_serviced_recursive_read" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Number of I/O operations read recursively as seen by the throttle policy.",
-        labels: vec![],
-        rename: Some("blkio_throttle_io_serviced_recursive_read_total"),
-    },
-    "blkio_throttle_io_serviced_recursive_write" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Number of I/O operations written recursively as seen by the throttle policy.",
-        labels: vec![],
-        rename: Some("blkio_throttle_io_serviced_recursive_write_total"),
-    },
-    "blkio_throttle_io_serviced_recursive_sync" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Synchronous I/O operations recursively as seen by the throttle policy.",
-        labels: vec![],
-        rename: Some("blkio_throttle_io_serviced_recursive_sync_total"),
-    },
-    "blkio_throttle_io_serviced_recursive_async" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Asynchronous I/O operations recursively as seen by the throttle policy.",
-        labels: vec![],
-        rename: Some("blkio_throttle_io_serviced_recursive_async_total"),
-    },
-    "blkio_throttle_io_serviced_recursive_discard" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Discarded I/O operations recursively as seen by the throttle policy.",
-        labels: vec![],
-        rename: Some("blkio_throttle_io_serviced_recursive_discard_total"),
-    },
-    "blkio_throttle_io_serviced_recursive_total" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Total recursive I/O operations as seen by the throttle policy.",
-        labels: vec![],
-        rename: Some("blkio_throttle_io_serviced_recursive_total"),
+    "blkio_throttle_io_serviced_recursive_read" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Number of I/O operations read recursively as seen by the throttle policy.",
+            labels: vec![],
+            rename: Some("blkio_throttle_io_serviced_recursive_read_total"),
+        },
+        unit: None,
+    },
+    "blkio_throttle_io_serviced_recursive_write" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Number of I/O operations written recursively as seen by the throttle policy.",
+            labels: vec![],
+            rename: Some("blkio_throttle_io_serviced_recursive_write_total"),
+        },
+        unit: None,
+    },
+    "blkio_throttle_io_serviced_recursive_sync" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Synchronous I/O operations recursively as seen by the throttle policy.",
+            labels: vec![],
+            rename: Some("blkio_throttle_io_serviced_recursive_sync_total"),
+        },
+        unit: None,
+    },
+    "blkio_throttle_io_serviced_recursive_async" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Asynchronous I/O operations recursively as seen by the throttle policy.",
+            labels: vec![],
+            rename: Some("blkio_throttle_io_serviced_recursive_async_total"),
+        },
+        unit: None,
+    },
+    "blkio_throttle_io_serviced_recursive_discard" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Discarded I/O operations recursively as seen by the throttle policy.",
+            labels: vec![],
+            rename: Some("blkio_throttle_io_serviced_recursive_discard_total"),
+        },
+        unit: None,
     },
     // Field: throttle.io_serviced_recursive_total: u64
-    "blkio_throttle_io_serviced_recursive_total" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "Total number of recursive I/O operations as seen by the throttle policy.",
-        labels: vec![],
-        rename: Some("blkio_throttle_io_serviced_recursive_total"),
+    "blkio_throttle_io_serviced_recursive_total" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Total number of recursive I/O operations as seen by the throttle policy.",
+            labels: vec![],
+            rename: Some("blkio_throttle_io_serviced_recursive_total"),
+        },
+        unit: None,
     },
     // Field: throttle.read_bps_device: Vec<BlkIoData>
-    // Nested BlkIoData fields prefixed with "blkio_throttle_read_bps_device_"
-    "blkio_throttle_read_bps_device_major" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The major number of the device (read BPS).",
-        labels: vec![],
-        rename: None,
-    },
-    "blkio_throttle_read_bps_device_minor" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The minor number of the device (read BPS).",
-        labels: vec![],
-        rename: None,
-    },
-    "blkio_throttle_read_bps_device_data" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The upper limit of bytes per second rate of read operations.",
-        labels: vec![],
-        rename: None,
+    // Nested BlkIoData fields, one series per device, prefixed with "blkio_throttle_read_bps_device_"
+    "blkio_throttle_read_bps_device" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "The upper limit of bytes per second rate of read operations.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
     },
     // Field: throttle.read_iops_device: Vec<BlkIoData>
-    // Nested BlkIoData fields prefixed with "blkio_throttle_read_iops_device_"
-    "blkio_throttle_read_iops_device_major" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The major number of the device (read IOPS).",
-        labels: vec![],
-        rename: None,
-    },
-    "blkio_throttle_read_iops_device_minor" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The minor number of the device (read IOPS).",
-        labels: vec![],
-        rename: None,
-    },
-    "blkio_throttle_read_iops_device_data" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The upper limit of I/O operations per second for read operations.",
-        labels: vec![],
-        rename: None,
+    // Nested BlkIoData fields, one series per device, prefixed with "blkio_throttle_read_iops_device_"
+    "blkio_throttle_read_iops_device" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "The upper limit of I/O operations per second for read operations.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
     },
     // Field: throttle.write_bps_device: Vec<BlkIoData>
-    // Nested BlkIoData fields prefixed with "blkio_throttle_write_bps_device_"
-    "blkio_throttle_write_bps_device_major" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The major number of the device (write BPS).",
-        labels: vec![],
-        rename: None,
-    },
-    "blkio_throttle_write_bps_device_minor" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The minor number of the device (write BPS).",
-        labels: vec![],
-        rename: None,
-    },
-    "blkio_throttle_write_bps_device_data" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The upper limit of bytes per second rate of write operations.",
-        labels: vec![],
-        rename: None,
+    // Nested BlkIoData fields, one series per device, prefixed with "blkio_throttle_write_bps_device_"
+    "blkio_throttle_write_bps_device" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "The upper limit of bytes per second rate of write operations.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
     },
     // Field: throttle.write_iops_device: Vec<BlkIoData>
-    // Nested BlkIoData fields prefixed with "blkio_throttle_write_iops_device_"
-    "blkio_throttle_write_iops_device_major" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The major number of the device (write IOPS).",
-        labels: vec![],
-        rename: None,
-    },
-    "blkio_throttle_write_iops_device_minor" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The minor number of the device (write IOPS).",
-        labels: vec![],
-        rename: None,
-    },
-    "blkio_throttle_write_iops_device_data" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The upper limit of I/O operations per second for write operations.",
-        labels: vec![],
-        rename: None,
+    // Nested BlkIoData fields, one series per device, prefixed with "blkio_throttle_write_iops_device_"
+    "blkio_throttle_write_iops_device" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "The upper limit of I/O operations per second for write operations.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
     },
     // End of throttle fields
     // Field: time: Vec<BlkIoData>
-    // Nested BlkIoData fields prefixed with "blkio_time_"
-    "blkio_time_major" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The major number of the device (time).",
-        labels: vec![],
-        rename: None,
-    },
-    "blkio_time_minor" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The minor number of the device (time).",
-        labels: vec![],
-        rename: None,
-    },
-    "blkio_time_data" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The total time the control group had access to the I/O devices.",
-        labels: vec![],
-        rename: None,
+    // Nested BlkIoData fields, one series per device, prefixed with "blkio_time_"
+    "blkio_time" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "The total time the control group had access to the I/O devices.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: Some(Unit::Milliseconds),
     },
     // Field: time_recursive: Vec<BlkIoData>
-    // Nested BlkIoData fields prefixed with "blkio_time_recursive_"
-    "blkio_time_recursive_major" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The major number of the device (time recursive).",
-        labels: vec![],
-        rename: None,
-    },
-    "blkio_time_recursive_minor" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The minor number of the device (time recursive).",
-        labels: vec![],
-        rename: None,
-    },
-    "blkio_time_recursive_data" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The total time the control group had access to the I/O devices, including descendants.",
-        labels: vec![],
-        rename: None,
+    // Nested BlkIoData fields, one series per device, prefixed with "blkio_time_recursive_"
+    "blkio_time_recursive" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "The total time the control group had access to the I/O devices, including descendants.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: Some(Unit::Milliseconds),
     },
     // Field: weight: u64
-    "blkio_weight" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The weight of this control group.",
-        labels: vec![],
-        rename: None,
+    "blkio_weight" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "The weight of this control group.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
     },
     // Field: weight_device: Vec<BlkIoData>
-    // Nested BlkIoData fields prefixed with "blkio_weight_device_"
-    "blkio_weight_device_major" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The major number of the device (weight).",
-        labels: vec![],
-        rename: None,
-    },
-    "blkio_weight_device_minor" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The minor number of the device (weight).",
-        labels: vec![],
-        rename: None,
-    },
-    "blkio_weight_device_data" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The weight data associated with the device.",
-        labels: vec![],
-        rename: None,
+    // Nested BlkIoData fields, one series per device, prefixed with "blkio_weight_device_"
+    "blkio_weight_device" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "The weight data associated with the device.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
     },
     // Field: io_stat: Vec<IoStat>
-    // Nested IoStat fields prefixed with "blkio_io_stat_"
-    "blkio_io_stat_major" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The major number of the device (io_stat).",
-        labels: vec![],
-        rename: None,
-    },
-    "blkio_io_stat_minor" => MetricDescriptor {
-        metric_type: MetricType::Gauge,
-        help: "The minor number of the device (io_stat).",
-        labels: vec![],
-        rename: None,
-    },
-    "blkio_io_stat_rbytes" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many bytes were read from the device.",
-        labels: vec![],
-        rename: Some("blkio_io_stat_rbytes_total"),
-    },
-    "blkio_io_stat_wbytes" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many bytes were written to the device.",
-        labels: vec![],
-        rename: Some("blkio_io_stat_wbytes_total"),
-    },
-    "blkio_io_stat_rios" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many IOPS were read from the device.",
-        labels: vec![],
-        rename: Some("blkio_io_stat_rios_total"),
-    },
-    "blkio_io_stat_wios" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many IOPS were written to the device.",
-        labels: vec![],
-        rename: Some("blkio_io_stat_wios_total"),
-    },
-    "blkio_io_stat_dbytes" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many discard bytes were read from the device.",
-        labels: vec![],
-        rename: Some("blkio_io_stat_dbytes_total"),
-    },
-    "blkio_io_stat_dios" => MetricDescriptor {
-        metric_type: MetricType::Counter,
-        help: "How many discard IOPS were written to the device.",
-        labels: vec![],
-        rename: Some("blkio_io_stat_dios_total"),
+    // Nested IoStat fields, one series per device, prefixed with "blkio_io_stat_"
+    "blkio_io_stat_rbytes" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many bytes were read from the device.",
+            labels: vec![],
+            rename: Some("blkio_io_stat_rbytes_total"),
+        },
+        unit: None,
+    },
+    "blkio_io_stat_wbytes" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many bytes were written to the device.",
+            labels: vec![],
+            rename: Some("blkio_io_stat_wbytes_total"),
+        },
+        unit: None,
+    },
+    "blkio_io_stat_rios" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many IOPS were read from the device.",
+            labels: vec![],
+            rename: Some("blkio_io_stat_rios_total"),
+        },
+        unit: None,
+    },
+    "blkio_io_stat_wios" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many IOPS were written to the device.",
+            labels: vec![],
+            rename: Some("blkio_io_stat_wios_total"),
+        },
+        unit: None,
+    },
+    "blkio_io_stat_dbytes" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many discard bytes were read from the device.",
+            labels: vec![],
+            rename: Some("blkio_io_stat_dbytes_total"),
+        },
+        unit: None,
+    },
+    "blkio_io_stat_dios" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many discard IOPS were written to the device.",
+            labels: vec![],
+            rename: Some("blkio_io_stat_dios_total"),
+        },
+        unit: None,
+    },
+    // CGROUP NETWORK METRICS (one series per interface, labeled with `interface`)
+    "network_receive_bytes" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Bytes received on the interface.",
+            labels: vec![],
+            rename: Some("network_receive_bytes_total"),
+        },
+        unit: None,
+    },
+    "network_receive_packets" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Packets received on the interface.",
+            labels: vec![],
+            rename: Some("network_receive_packets_total"),
+        },
+        unit: None,
+    },
+    "network_receive_errors" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Errors while receiving on the interface.",
+            labels: vec![],
+            rename: Some("network_receive_errors_total"),
+        },
+        unit: None,
+    },
+    "network_receive_packets_dropped" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Packets dropped while receiving on the interface.",
+            labels: vec![],
+            rename: Some("network_receive_packets_dropped_total"),
+        },
+        unit: None,
+    },
+    "network_transmit_bytes" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Bytes transmitted on the interface.",
+            labels: vec![],
+            rename: Some("network_transmit_bytes_total"),
+        },
+        unit: None,
+    },
+    "network_transmit_packets" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Packets transmitted on the interface.",
+            labels: vec![],
+            rename: Some("network_transmit_packets_total"),
+        },
+        unit: None,
+    },
+    "network_transmit_errors" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Errors while transmitting on the interface.",
+            labels: vec![],
+            rename: Some("network_transmit_errors_total"),
+        },
+        unit: None,
+    },
+    "network_transmit_packets_dropped" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Packets dropped while transmitting on the interface.",
+            labels: vec![],
+            rename: Some("network_transmit_packets_dropped_total"),
+        },
+        unit: None,
+    },
+    // CGROUP HUGETLB METRICS (one series per page size, labeled with `pagesize`)
+    "hugetlb_usage_bytes" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Current usage of huge pages of the page size in bytes.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "hugetlb_limit_bytes" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Limit of huge page usage of the page size in bytes.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "hugetlb_max_usage_bytes" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Maximum recorded usage of huge pages of the page size in bytes. cgroup v1 only.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "hugetlb_failcnt" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many times an allocation of huge pages of the page size failed because of the limit.",
+            labels: vec![],
+            rename: Some("hugetlb_failcnt_total"),
+        },
+        unit: None,
+    },
+    // CGROUP PIDS METRICS
+    "pids_current" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "The number of processes and threads in the control group and its descendants.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "pids_max" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "The maximum number of processes and threads allowed in the control group. Not reported when unlimited.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "pids_events_max" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "How many times a fork or clone failed because the process number limit was reached.",
+            labels: vec![],
+            rename: Some("pids_events_max_total"),
+        },
+        unit: None,
+    },
+    // CGROUP PRESSURE METRICS (cpu.pressure, memory.pressure, io.pressure)
+    "pressure_cpu_some_avg10" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Percentage of time over the last 10 seconds in which some tasks were stalled on CPU.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "pressure_cpu_some_avg60" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Percentage of time over the last 60 seconds in which some tasks were stalled on CPU.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "pressure_cpu_some_avg300" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Percentage of time over the last 300 seconds in which some tasks were stalled on CPU.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "pressure_cpu_some_total" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Total time in seconds in which some tasks were stalled on CPU.",
+            labels: vec![],
+            rename: Some("pressure_cpu_some_seconds_total"),
+        },
+        unit: Some(Unit::Seconds),
+    },
+    "pressure_cpu_full_avg10" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Percentage of time over the last 10 seconds in which all non-idle tasks were stalled on CPU.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "pressure_cpu_full_avg60" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Percentage of time over the last 60 seconds in which all non-idle tasks were stalled on CPU.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "pressure_cpu_full_avg300" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Percentage of time over the last 300 seconds in which all non-idle tasks were stalled on CPU.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "pressure_cpu_full_total" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Total time in seconds in which all non-idle tasks were stalled on CPU.",
+            labels: vec![],
+            rename: Some("pressure_cpu_full_seconds_total"),
+        },
+        unit: Some(Unit::Seconds),
+    },
+    "pressure_memory_some_avg10" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Percentage of time over the last 10 seconds in which some tasks were stalled on memory.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "pressure_memory_some_avg60" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Percentage of time over the last 60 seconds in which some tasks were stalled on memory.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "pressure_memory_some_avg300" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Percentage of time over the last 300 seconds in which some tasks were stalled on memory.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "pressure_memory_some_total" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Total time in seconds in which some tasks were stalled on memory.",
+            labels: vec![],
+            rename: Some("pressure_memory_some_seconds_total"),
+        },
+        unit: Some(Unit::Seconds),
+    },
+    "pressure_memory_full_avg10" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Percentage of time over the last 10 seconds in which all non-idle tasks were stalled on memory.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "pressure_memory_full_avg60" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Percentage of time over the last 60 seconds in which all non-idle tasks were stalled on memory.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "pressure_memory_full_avg300" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Percentage of time over the last 300 seconds in which all non-idle tasks were stalled on memory.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "pressure_memory_full_total" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Total time in seconds in which all non-idle tasks were stalled on memory.",
+            labels: vec![],
+            rename: Some("pressure_memory_full_seconds_total"),
+        },
+        unit: Some(Unit::Seconds),
+    },
+    "pressure_io_some_avg10" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Percentage of time over the last 10 seconds in which some tasks were stalled on IO.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "pressure_io_some_avg60" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Percentage of time over the last 60 seconds in which some tasks were stalled on IO.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "pressure_io_some_avg300" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Percentage of time over the last 300 seconds in which some tasks were stalled on IO.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "pressure_io_some_total" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Total time in seconds in which some tasks were stalled on IO.",
+            labels: vec![],
+            rename: Some("pressure_io_some_seconds_total"),
+        },
+        unit: Some(Unit::Seconds),
+    },
+    "pressure_io_full_avg10" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Percentage of time over the last 10 seconds in which all non-idle tasks were stalled on IO.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "pressure_io_full_avg60" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Percentage of time over the last 60 seconds in which all non-idle tasks were stalled on IO.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "pressure_io_full_avg300" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Gauge,
+            help: "Percentage of time over the last 300 seconds in which all non-idle tasks were stalled on IO.",
+            labels: vec![],
+            rename: None,
+        },
+        unit: None,
+    },
+    "pressure_io_full_total" => Descriptor {
+        metric: MetricDescriptor {
+            metric_type: MetricType::Counter,
+            help: "Total time in seconds in which all non-idle tasks were stalled on IO.",
+            labels: vec![],
+            rename: Some("pressure_io_full_seconds_total"),
+        },
+        unit: Some(Unit::Seconds),
     },
 };
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn time_units_match_metric_names() {
+        for (key, descriptor) in DESCRIPTORS.entries() {
+            assert!(descriptor.unit_matches_name(key), "{key}");
+        }
+        assert_eq!(METADATA.len(), DESCRIPTORS.len());
+    }
+}
@@ -0,0 +1,369 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, PoisonError},
+    time::{Duration, Instant},
+};
+
+use super::{Proc, states::ZOMBIE};
+
+/// The raw counters of a process, in the units of procfs.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ProcCounters {
+    /// CPU times in clock ticks.
+    pub utime: u64,
+    pub stime: u64,
+    pub io_read_bytes: u64,
+    pub io_write_bytes: u64,
+    pub io_rchar: u64,
+    pub io_wchar: u64,
+    pub io_syscr: u64,
+    pub io_syscw: u64,
+    pub io_cancelled_write_bytes: u64,
+    pub majflt: u64,
+    pub minflt: u64,
+    pub voluntary_ctxt_switches: u64,
+    pub nonvoluntary_ctxt_switches: u64,
+    pub run_delay_ns: u64,
+    pub blkio_ticks: u64,
+}
+
+impl ProcCounters {
+    pub fn from_proc(process: &Proc) -> Self {
+        let stat = process.stat();
+        let mut counters = Self {
+            utime: stat.utime,
+            stime: stat.stime,
+            majflt: stat.majflt,
+            minflt: stat.minflt,
+            blkio_ticks: stat.delayacct_blkio_ticks.unwrap_or_default(),
+            ..Self::default()
+        };
+        if let Some(io_stat) = process.io() {
+            counters.io_read_bytes = io_stat.read_bytes;
+            counters.io_write_bytes = io_stat.write_bytes;
+            counters.io_rchar = io_stat.rchar;
+            counters.io_wchar = io_stat.wchar;
+            counters.io_syscr = io_stat.syscr;
+            counters.io_syscw = io_stat.syscw;
+            counters.io_cancelled_write_bytes = io_stat.cancelled_write_bytes;
+        }
+        if let Some(sched) = process.sched() {
+            counters.voluntary_ctxt_switches = sched.voluntary_ctxt_switches;
+            counters.nonvoluntary_ctxt_switches = sched.nonvoluntary_ctxt_switches;
+            counters.run_delay_ns = sched.run_delay_ns.unwrap_or_default();
+        }
+        counters
+    }
+
+    pub fn add(&mut self, other: &ProcCounters) {
+        self.utime += other.utime;
+        self.stime += other.stime;
+        self.io_read_bytes += other.io_read_bytes;
+        self.io_write_bytes += other.io_write_bytes;
+        self.io_rchar += other.io_rchar;
+        self.io_wchar += other.io_wchar;
+        self.io_syscr += other.io_syscr;
+        self.io_syscw += other.io_syscw;
+        self.io_cancelled_write_bytes += other.io_cancelled_write_bytes;
+        self.majflt += other.majflt;
+        self.minflt += other.minflt;
+        self.voluntary_ctxt_switches += other.voluntary_ctxt_switches;
+        self.nonvoluntary_ctxt_switches += other.nonvoluntary_ctxt_switches;
+        self.run_delay_ns += other.run_delay_ns;
+        self.blkio_ticks += other.blkio_ticks;
+    }
+}
+
+/// Identifies a process across scrapes. The start time tells apart a reused pid.
+pub type ProcKey = (i32, u64);
+
+/// Identifies a process group by its name and its labels.
+pub type GroupKey = (String, Vec<(String, String)>);
+
+/// How long the history of a group is kept after the last scrape that found processes in it, so
+/// that its counters don't reset when it is briefly empty, e.g. while a service restarts.
+const GROUP_RETENTION: Duration = Duration::from_secs(5 * 60);
+
+/// The totals of a group, computed from the history of its processes.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct GroupTotals {
+    pub counters: ProcCounters,
+    /// How long the oldest zombie of the group has been a zombie, 0 when there are none.
+    pub oldest_zombie_age_seconds: f64,
+}
+
+/// The processes of a group found in a scrape.
+#[derive(Debug, Default)]
+struct GroupScrape {
+    live: HashMap<ProcKey, ProcCounters>,
+    zombies: Vec<ProcKey>,
+}
+
+impl GroupScrape {
+    fn from_procs(procs: &[Proc]) -> Self {
+        let mut scrape = Self::default();
+        for process in procs {
+            let key = (process.pid(), process.stat().starttime);
+            scrape.live.insert(key, ProcCounters::from_proc(process));
+            if process.stat().state == ZOMBIE {
+                scrape.zombies.push(key);
+            }
+        }
+        scrape
+    }
+}
+
+/// The counters of the processes of a group seen in the previous scrapes, and the sum of the
+/// counters of the processes that exited since.
+#[derive(Debug)]
+struct GroupHistory {
+    live: HashMap<ProcKey, ProcCounters>,
+    retired: ProcCounters,
+    /// When each zombie of the group was first seen as a zombie.
+    zombies: HashMap<ProcKey, Instant>,
+    last_seen: Instant,
+}
+
+impl GroupHistory {
+    fn new(now: Instant) -> Self {
+        Self {
+            live: HashMap::new(),
+            retired: ProcCounters::default(),
+            zombies: HashMap::new(),
+            last_seen: now,
+        }
+    }
+
+    /// Retires the processes that left the group and returns the totals of the group, which
+    /// never decrease while the group exists.
+    ///
+    /// A process can be missing from a scrape without having left the group, e.g. when one of
+    /// its files couldn't be read. Its last counters are kept as long as `in_group` tells that it
+    /// still runs and matches the group, so it isn't counted in both the retired and the live
+    /// counters once it is found again.
+    fn update(
+        &mut self,
+        mut live: HashMap<ProcKey, ProcCounters>,
+        in_group: impl Fn(ProcKey) -> bool,
+    ) -> ProcCounters {
+        for (key, counters) in &self.live {
+            if live.contains_key(key) {
+                continue;
+            }
+            if in_group(*key) {
+                live.insert(*key, *counters);
+            } else {
+                self.retired.add(counters);
+            }
+        }
+        self.live = live;
+
+        let mut totals = self.retired;
+        for counters in self.live.values() {
+            totals.add(counters);
+        }
+        totals
+    }
+
+    /// Returns how long the oldest of the `zombies` has been a zombie, in seconds.
+    ///
+    /// The kernel doesn't tell when a process exited, so a zombie is timed from the first scrape
+    /// that saw it, which is at most one scrape interval late.
+    fn oldest_zombie_age(&mut self, zombies: &[ProcKey], now: Instant) -> f64 {
+        self.zombies.retain(|key, _| zombies.contains(key));
+        for key in zombies {
+            self.zombies.entry(*key).or_insert(now);
+        }
+        self.zombies
+            .values()
+            .map(|since| now.duration_since(*since).as_secs_f64())
+            .fold(0.0, f64::max)
+    }
+}
+
+/// The counters of the process groups across scrapes, so the exported totals don't go down
+/// when a process of a group exits. Cheap to clone and shared between the scrapes.
+#[derive(Debug, Clone, Default)]
+pub struct ProcessHistory(Arc<Mutex<HashMap<GroupKey, GroupHistory>>>);
+
+impl ProcessHistory {
+    /// Updates the history with the processes of the groups found in a scrape, and returns the
+    /// totals of each group. `in_group` tells whether a process missing from its group in this
+    /// scrape still runs and matches the group.
+    pub fn update<'g>(
+        &self,
+        groups: impl Iterator<Item = (&'g GroupKey, &'g Vec<Proc>)>,
+        in_group: impl Fn(ProcKey, &GroupKey) -> bool,
+    ) -> HashMap<GroupKey, GroupTotals> {
+        let groups = groups.map(|(key, procs)| (key, GroupScrape::from_procs(procs)));
+        self.update_at(groups, in_group, Instant::now())
+    }
+
+    /// Groups that aren't found for longer than `GROUP_RETENTION` are forgotten, since their
+    /// series have gone stale.
+    fn update_at<'g>(
+        &self,
+        groups: impl Iterator<Item = (&'g GroupKey, GroupScrape)>,
+        in_group: impl Fn(ProcKey, &GroupKey) -> bool,
+        now: Instant,
+    ) -> HashMap<GroupKey, GroupTotals> {
+        let mut history = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        let totals = groups
+            .map(|(key, scrape)| {
+                let group = history
+                    .entry(key.clone())
+                    .or_insert_with(|| GroupHistory::new(now));
+                group.last_seen = now;
+                let totals = GroupTotals {
+                    counters: group.update(scrape.live, |process| in_group(process, key)),
+                    oldest_zombie_age_seconds: group.oldest_zombie_age(&scrape.zombies, now),
+                };
+                (key.clone(), totals)
+            })
+            .collect();
+        history.retain(|_, group| now.duration_since(group.last_seen) < GROUP_RETENTION);
+        totals
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn counters(utime: u64, majflt: u64) -> ProcCounters {
+        ProcCounters {
+            utime,
+            majflt,
+            ..ProcCounters::default()
+        }
+    }
+
+    #[test]
+    fn totals_survive_process_exits() {
+        let exited = |_| false;
+        let mut history = GroupHistory::new(Instant::now());
+        let totals = history.update(
+            HashMap::from([((100, 1), counters(10, 1)), ((101, 2), counters(20, 2))]),
+            exited,
+        );
+        assert_eq!(totals, counters(30, 3));
+
+        // 101 exits and its pid is reused by a new process.
+        let totals = history.update(
+            HashMap::from([((100, 1), counters(15, 1)), ((101, 9), counters(1, 0))]),
+            exited,
+        );
+        assert_eq!(totals, counters(36, 3));
+
+        let totals = history.update(HashMap::new(), exited);
+        assert_eq!(totals, counters(36, 3));
+    }
+
+    fn scrape(live: &[(ProcKey, ProcCounters)]) -> GroupScrape {
+        GroupScrape {
+            live: live.iter().copied().collect(),
+            zombies: vec![],
+        }
+    }
+
+    #[test]
+    fn missing_process_that_is_still_in_the_group_is_not_retired() {
+        let mut history = GroupHistory::new(Instant::now());
+        let totals = history.update(
+            HashMap::from([((100, 1), counters(10, 1)), ((101, 2), counters(20, 2))]),
+            |_| false,
+        );
+        assert_eq!(totals, counters(30, 3));
+
+        // 101 couldn't be read in this scrape, but it still runs and matches the group.
+        let totals = history.update(HashMap::from([((100, 1), counters(12, 1))]), |key| {
+            key == (101, 2)
+        });
+        assert_eq!(totals, counters(32, 3));
+
+        // 101 is back, and is only counted once.
+        let totals = history.update(
+            HashMap::from([((100, 1), counters(12, 1)), ((101, 2), counters(25, 2))]),
+            |_| false,
+        );
+        assert_eq!(totals, counters(37, 3));
+    }
+
+    #[test]
+    fn missing_process_that_left_the_group_is_retired() {
+        let mut history = GroupHistory::new(Instant::now());
+        let totals = history.update(
+            HashMap::from([((100, 1), counters(10, 1)), ((101, 2), counters(20, 2))]),
+            |_| false,
+        );
+        assert_eq!(totals, counters(30, 3));
+
+        // 101 still runs, but it changed its name and now matches another group.
+        let totals = history.update(HashMap::from([((100, 1), counters(12, 1))]), |_| false);
+        assert_eq!(totals, counters(32, 3));
+
+        // It keeps running elsewhere, and isn't kept in the live counters of this group.
+        let totals = history.update(HashMap::from([((100, 1), counters(12, 1))]), |_| true);
+        assert_eq!(totals, counters(32, 3));
+        assert_eq!(history.live.len(), 1);
+    }
+
+    #[test]
+    fn group_history_survives_empty_scrapes() {
+        let history = ProcessHistory::default();
+        let key: GroupKey = ("worker".to_string(), vec![]);
+        let start = Instant::now();
+        let totals = history.update_at(
+            [(&key, scrape(&[((100, 1), counters(10, 1))]))].into_iter(),
+            |_, _| false,
+            start,
+        );
+        assert_eq!(totals[&key].counters, counters(10, 1));
+
+        // The group has no processes while it restarts.
+        let totals = history.update_at(
+            [].into_iter(),
+            |_, _| false,
+            start + Duration::from_secs(15),
+        );
+        assert!(totals.is_empty());
+
+        let totals = history.update_at(
+            [(&key, scrape(&[((102, 3), counters(5, 0))]))].into_iter(),
+            |_, _| false,
+            start + Duration::from_secs(30),
+        );
+        assert_eq!(totals[&key].counters, counters(15, 1));
+
+        // The group is forgotten once it has been empty for longer than the retention.
+        let later = start + Duration::from_secs(30) + GROUP_RETENTION;
+        history.update_at([].into_iter(), |_, _| false, later);
+        let totals = history.update_at(
+            [(&key, scrape(&[((103, 4), counters(1, 0))]))].into_iter(),
+            |_, _| false,
+            later,
+        );
+        assert_eq!(totals[&key].counters, counters(1, 0));
+    }
+
+    #[test]
+    fn zombies_are_timed_from_when_they_were_first_seen() {
+        let start = Instant::now();
+        let mut history = GroupHistory::new(start);
+        let age = history.oldest_zombie_age(&[(100, 1)], start);
+        assert!(age.abs() < f64::EPSILON);
+
+        let age = history.oldest_zombie_age(&[(100, 1), (101, 2)], start + Duration::from_secs(15));
+        assert!((age - 15.0).abs() < f64::EPSILON);
+
+        // 100 is reaped, 101 has been a zombie since the previous scrape.
+        let age = history.oldest_zombie_age(&[(101, 2)], start + Duration::from_secs(45));
+        assert!((age - 30.0).abs() < f64::EPSILON);
+
+        let age = history.oldest_zombie_age(&[], start + Duration::from_secs(60));
+        assert!(age.abs() < f64::EPSILON);
+        assert!(history.zombies.is_empty());
+    }
+}
@@ -1,11 +1,15 @@
 use crate::{
+    labels::render_labels,
     matcher::{MatchableProcessConfig, NameMatcher},
-    procs::{Proc, metrics::ProcessMetrics},
+    procs::{GroupKey, Proc, ProcessHistory, counters::ProcKey, metrics::ProcessMetrics},
     render::MatchGroup,
 };
 use new_string_template::template::Template;
 use procfs::process::Process;
-use std::{collections::HashMap, result::Result};
+use std::{
+    collections::{BTreeMap, HashMap},
+    result::Result,
+};
 use tokio::sync::mpsc;
 use tokio_stream::{Stream, StreamExt as _, wrappers::ReceiverStream};
 use tracing::{error, trace};
@@ -14,10 +18,12 @@ const NAMESPACE: &str = "process";
 
 pub fn discover_procs_metrics(
     configs: &[MatchableProcessConfig],
+    history: &ProcessHistory,
 ) -> impl Stream<Item = MatchGroup<ProcessMetrics>> + 'static {
     let (send, recv) = mpsc::channel(10);
     let configs = configs.to_owned();
-    std::thread::spawn(move || discover_thread(&configs, &send));
+    let history = history.clone();
+    std::thread::spawn(move || discover_thread(&configs, &history, &send));
     let stream = ReceiverStream::new(recv);
     stream.filter_map(|match_group| match match_group {
         Ok(match_group) => Some(match_group),
@@ -30,6 +36,7 @@ pub fn discover_procs_metrics(
 
 fn discover_thread(
     configs: &[MatchableProcessConfig],
+    history: &ProcessHistory,
     sender: &mpsc::Sender<anyhow::Result<MatchGroup<ProcessMetrics>>>,
 ) {
     let mut groups = HashMap::new();
@@ -47,10 +54,23 @@ fn discover_thread(
         }
     }
 
-    for (name, group) in groups {
-        let (data, config) = group.into_parts();
-        let metrics = ProcessMetrics::from_processes(data.into_iter(), &name);
-        let match_group = MatchGroup::new(vec![metrics], config);
+    let groups: Vec<_> = groups
+        .into_iter()
+        .map(|(name, group)| (name, group.into_parts()))
+        .collect();
+    let mut totals = history.update(
+        groups.iter().map(|(name, (data, _, _))| (name, data)),
+        |process, group| in_group(process, group, configs),
+    );
+
+    for (key, (data, config, rules)) in groups {
+        let mut metrics = ProcessMetrics::from_processes(data.into_iter(), &key.0);
+        if let Some(totals) = totals.remove(&key) {
+            metrics.set_counters(&totals.counters);
+            metrics.oldest_zombie_age_seconds = Some(totals.oldest_zombie_age_seconds);
+        }
+        metrics.labels = key.1;
+        let match_group = MatchGroup::new(vec![metrics], config, rules);
         let _ = sender.blocking_send(Ok(match_group));
     }
 }
@@ -58,52 +78,84 @@ fn discover_thread(
 fn process_process(
     process: Process,
     configs: &[MatchableProcessConfig],
-    groups: &mut HashMap<String, MatchGroup<Proc>>,
+    groups: &mut HashMap<GroupKey, MatchGroup<Proc>>,
 ) -> anyhow::Result<()> {
     let process = Proc::try_from(process)?;
     for config in configs {
-        let proc_value = process.value_for_matcher(&config.match_by);
-        let group_name = config.match_by.name();
-        if let Some(name) = config
-            .match_by
-            .matcher()
-            .matching_group_name(proc_value, group_name, &process)
-        {
-            let group = groups.entry(name.clone()).or_insert_with(|| {
+        if let Some(key) = config_group(config, &process) {
+            let group = groups.entry(key).or_insert_with(|| {
                 let mut metrics_config = config.metrics.clone();
                 if metrics_config.namespace.is_none() {
                     metrics_config.namespace = Some(NAMESPACE.to_string());
                 }
-                MatchGroup::new(vec![], metrics_config)
+                MatchGroup::new(vec![], metrics_config, config.rules.clone())
             });
             let mut process = process.clone();
-            process.gather_remaining_info()?;
+            process.gather_remaining_info(&config.reads)?;
             group.insert(process);
         }
     }
     Ok(())
 }
 
+/// Returns the group of the process for a config, or `None` when it doesn't match.
+fn config_group(config: &MatchableProcessConfig, process: &Proc) -> Option<GroupKey> {
+    let proc_value = process.value_for_matcher(&config.match_by);
+    let group_name = config.match_by.name();
+    let labels = config.match_by.labels();
+    config
+        .match_by
+        .matcher()
+        .matching_group(proc_value, group_name, labels, process)
+}
+
+/// Returns whether a process that is missing from its group in a scrape still runs, with the
+/// same start time so its pid wasn't reused, and still matches the group.
+fn in_group(
+    (pid, starttime): ProcKey,
+    group: &GroupKey,
+    configs: &[MatchableProcessConfig],
+) -> bool {
+    Process::new(pid)
+        .and_then(Proc::try_from)
+        .ok()
+        .filter(|process| process.stat().starttime == starttime)
+        .is_some_and(|process| {
+            configs
+                .iter()
+                .any(|config| config_group(config, &process).as_ref() == Some(group))
+        })
+}
+
 impl NameMatcher {
-    fn matching_group_name(
+    /// Returns the name and the labels of the group of the process, or `None` when it doesn't
+    /// match. With a glob, the name and the labels are used as they are.
+    fn matching_group(
         &self,
         proc_value: &str,
         group_name: &str,
+        labels: &BTreeMap<String, String>,
         process: &Proc,
-    ) -> Option<String> {
+    ) -> Option<GroupKey> {
         match self {
-            Self::Glob(pattern) => pattern.matches(proc_value).then(|| group_name.into()),
+            Self::Glob(pattern) => pattern.matches(proc_value).then(|| {
+                let labels = labels
+                    .iter()
+                    .map(|(label, value)| (label.clone(), value.clone()))
+                    .collect();
+                (group_name.into(), labels)
+            }),
             Self::Regex(regex) => {
                 if let Some(captures) = regex.captures(proc_value) {
-                    let mut variables = init_variables_from_process(process);
+                    let mut variables: HashMap<&str, String> = init_variables_from_process(process);
                     for name in regex.capture_names() {
                         let Some(name) = name else { continue };
                         if let Some(matched) = captures.name(name) {
-                            variables.insert(name.to_string(), matched.as_str().to_string());
+                            variables.insert(name, matched.as_str().to_string());
                         }
                     }
-                    let template = Template::new(group_name);
-                    Some(template.render_nofail_string(&variables))
+                    let name = Template::new(group_name).render_nofail(&variables);
+                    Some((name, render_labels(labels, &variables)))
                 } else {
                     None
                 }
@@ -112,10 +164,10 @@ impl NameMatcher {
     }
 }
 
-fn init_variables_from_process(process: &Proc) -> HashMap<String, String> {
+fn init_variables_from_process(process: &Proc) -> HashMap<&'static str, String> {
     let mut variables = HashMap::new();
-    variables.insert("pid".into(), process.pid().to_string());
-    variables.insert("exe".into(), process.exe().to_owned());
-    variables.insert("comm".into(), process.comm().to_owned());
+    variables.insert("pid", process.pid().to_string());
+    variables.insert("exe", process.exe().to_owned());
+    variables.insert("comm", process.comm().to_owned());
     variables
 }
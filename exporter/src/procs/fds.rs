@@ -0,0 +1,107 @@
+use procfs::process::{FDTarget, LimitValue, Process};
+
+use crate::{procs::metrics::ProcessSeries, render::LabeledSeries};
+
+const TYPE_LABEL: &str = "type";
+
+/// The open file descriptors of a process by the type of their target.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct FdTypes {
+    /// Paths, including device files.
+    pub file: u64,
+    pub socket: u64,
+    pub pipe: u64,
+    /// Anonymous inodes other than eventfds, such as epoll, timerfd and signalfd.
+    pub anon_inode: u64,
+    pub eventfd: u64,
+    /// Everything else, such as memfds and network namespaces.
+    pub other: u64,
+}
+
+impl FdTypes {
+    pub fn from_targets<'t>(targets: impl IntoIterator<Item = &'t FDTarget>) -> Self {
+        let mut types = Self::default();
+        for target in targets {
+            let count = match target {
+                FDTarget::Path(_) => &mut types.file,
+                FDTarget::Socket(_) => &mut types.socket,
+                FDTarget::Pipe(_) => &mut types.pipe,
+                FDTarget::AnonInode(name) if name == "[eventfd]" => &mut types.eventfd,
+                FDTarget::AnonInode(_) => &mut types.anon_inode,
+                FDTarget::Net(_) | FDTarget::MemFD(_) | FDTarget::Other(..) => &mut types.other,
+            };
+            *count += 1;
+        }
+        types
+    }
+
+    pub fn add(&mut self, other: &FdTypes) {
+        self.file += other.file;
+        self.socket += other.socket;
+        self.pipe += other.pipe;
+        self.anon_inode += other.anon_inode;
+        self.eventfd += other.eventfd;
+        self.other += other.other;
+    }
+
+    /// Returns one series per type, labeled with the type.
+    pub fn into_series(self) -> Vec<LabeledSeries<ProcessSeries>> {
+        [
+            ("file", self.file),
+            ("socket", self.socket),
+            ("pipe", self.pipe),
+            ("anon_inode", self.anon_inode),
+            ("eventfd", self.eventfd),
+            ("other", self.other),
+        ]
+        .into_iter()
+        .map(|(fd_type, count)| LabeledSeries {
+            labels: vec![(TYPE_LABEL, fd_type.to_string())],
+            metrics: ProcessSeries::FdsByType {
+                num_fds_by_type: count,
+            },
+        })
+        .collect()
+    }
+}
+
+/// Returns the soft limit of open files of the process. `None` when it is unlimited.
+pub fn max_fds(process: &Process) -> Option<u64> {
+    match process.limits().ok()?.max_open_files.soft_limit {
+        LimitValue::Value(limit) => Some(limit),
+        LimitValue::Unlimited => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn classify_fd_targets() {
+        let targets = [
+            FDTarget::Path(PathBuf::from("/dev/null")),
+            FDTarget::Path(PathBuf::from("/var/log/app.log")),
+            FDTarget::Socket(1234),
+            FDTarget::Pipe(5678),
+            FDTarget::AnonInode("[eventfd]".to_string()),
+            FDTarget::AnonInode("[eventpoll]".to_string()),
+            FDTarget::MemFD("buffer".to_string()),
+        ];
+        assert_eq!(
+            FdTypes::from_targets(&targets),
+            FdTypes {
+                file: 2,
+                socket: 1,
+                pipe: 1,
+                anon_inode: 1,
+                eventfd: 1,
+                other: 1,
+            }
+        );
+    }
+}
@@ -1,26 +1,99 @@
-use super::Proc;
-use crate::render::Named;
+use super::{
+    Proc, counters::ProcCounters, fds::FdTypes, smaps::SmapsMemory, sockets::SocketCounter,
+    states::StateCounter,
+};
+use crate::{
+    render::{Labeled, LabeledSeries, Named},
+    units::{nanos_to_seconds, ticks_to_seconds},
+};
 use procfs::WithCurrentSystemInfo as _;
 use saturating_cast::SaturatingCast as _;
 use serde::Serialize;
 
+/// The names of the metrics of [`ProcessMetrics`] and [`ProcessSeries`] without the namespace.
+/// Reading the processes of a cgroup is skipped when none of them is included.
+pub const PROCESS_METRICS: &[&str] = &[
+    "rss",
+    "pss_bytes",
+    "uss_bytes",
+    "swap_bytes",
+    "shared_bytes",
+    "utime_seconds_total",
+    "stime_seconds_total",
+    "cpu_seconds_total",
+    "memory_usage_bytes",
+    "num_fds",
+    "max_fds",
+    "fd_utilization_ratio",
+    "num_fds_by_type",
+    "sockets",
+    "num_procs",
+    "procs_by_state",
+    "num_threads",
+    "io_read_bytes_total",
+    "io_write_bytes_total",
+    "io_read_chars_total",
+    "io_write_chars_total",
+    "io_read_syscalls_total",
+    "io_write_syscalls_total",
+    "io_cancelled_write_bytes_total",
+    "major_page_faults_total",
+    "minor_page_faults_total",
+    "voluntary_context_switches_total",
+    "nonvoluntary_context_switches_total",
+    "scheduler_wait_seconds_total",
+    "io_delay_seconds_total",
+    "start_time",
+];
+
 #[derive(Serialize, Default, Debug, Clone)]
 pub struct ProcessMetrics {
     #[serde(skip)]
     pub name: String,
+    /// The labels rendered from the `labels` of the matcher.
+    #[serde(skip)]
+    pub labels: Vec<(String, String)>,
+    /// Series that need their own labels, such as the fds by type or the socket counts by
+    /// protocol and state.
+    #[serde(skip)]
+    pub series: Vec<LabeledSeries<ProcessSeries>>,
 
     pub rss: u64,
-    pub utime: u64,
-    pub stime: u64,
-    pub cpu_seconds_total: u64,
+    /// The sums of `/proc/<pid>/smaps_rollup`, only read when enabled in the metrics config.
+    pub pss_bytes: Option<u64>,
+    pub uss_bytes: Option<u64>,
+    pub swap_bytes: Option<u64>,
+    pub shared_bytes: Option<u64>,
+    pub utime: f64,
+    pub stime: f64,
+    pub cpu_seconds_total: f64,
     pub memory_usage_bytes: u64,
     pub num_fds: u64,
+    /// The open files limit of the process with the highest `fd_utilization_ratio`.
+    pub max_fds: Option<u64>,
+    /// The highest ratio of open fds to the open files limit among the processes.
+    pub fd_utilization_ratio: Option<f64>,
     pub num_procs: u64,
+    /// How long the oldest zombie has been a zombie, 0 when there are none. Only set for process
+    /// groups, whose zombies are followed across scrapes by the `ProcessHistory`.
+    pub oldest_zombie_age_seconds: Option<f64>,
     pub num_threads: u64,
     pub io_read_bytes_total: u64,
     pub io_write_bytes_total: u64,
+    /// The bytes passed to read and write syscalls, whether or not they hit the disk.
+    pub io_read_chars_total: u64,
+    pub io_write_chars_total: u64,
+    pub io_read_syscalls_total: u64,
+    pub io_write_syscalls_total: u64,
+    pub io_cancelled_write_bytes_total: u64,
     pub major_page_faults_total: u64,
     pub minor_page_faults_total: u64,
+    pub voluntary_context_switches_total: u64,
+    pub nonvoluntary_context_switches_total: u64,
+    /// Time spent waiting on a run queue, from `/proc/<pid>/schedstat`.
+    pub scheduler_wait_seconds_total: f64,
+    /// Time spent waiting for block IO, from `delayacct_blkio_ticks` in `/proc/<pid>/stat`.
+    pub io_delay_seconds_total: f64,
     pub start_time: Option<i64>,
 }
 
@@ -34,58 +107,169 @@ impl ProcessMetrics {
         };
 
         let mut sum_rss_of_procs = 0;
-        let mut sum_utime = 0;
-        let mut sum_stime = 0;
-        let mut sum_io_read_bytes = 0;
-        let mut sum_io_write_bytes = 0;
+        let mut sum_counters = ProcCounters::default();
         let mut sum_fds = 0;
         let mut sum_procs = 0;
         let mut sum_threads = 0;
-        let mut sum_majflt = 0;
-        let mut sum_minflt = 0;
         let mut min_start_time = None;
         let page_size = procfs::page_size();
+        let mut sockets: Option<SocketCounter> = None;
+        let mut fd_types: Option<FdTypes> = None;
+        let mut states = StateCounter::default();
+        let mut most_saturated: Option<(f64, u64)> = None;
+        let mut sum_smaps: Option<SmapsMemory> = None;
+        let mut all_have_smaps = true;
 
         for process in proc_iter {
             let stat = process.stat();
-            sum_utime += stat.utime;
-            sum_stime += stat.stime;
+            sum_counters.add(&ProcCounters::from_proc(&process));
             sum_rss_of_procs += stat.rss * page_size;
             sum_threads += stat.num_threads.saturating_cast::<u64>();
-            sum_majflt += stat.majflt;
-            sum_minflt += stat.minflt;
+            states.add(stat.state);
             if let Ok(start_time) = stat.starttime().get().map(|t| t.timestamp()) {
                 min_start_time = Some(min_start_time.unwrap_or(i64::MAX).min(start_time));
             }
 
-            if let Some(io_stat) = process.io() {
-                sum_io_read_bytes += io_stat.read_bytes;
-                sum_io_write_bytes += io_stat.write_bytes;
-            }
             if let Some(fd_count) = process.fd_count() {
                 sum_fds += *fd_count as u64;
+                if let Some(max_fds) = process.max_fds().filter(|max_fds| *max_fds > 0) {
+                    let ratio = fd_ratio(*fd_count, max_fds);
+                    if most_saturated.is_none_or(|(max_ratio, _)| ratio > max_ratio) {
+                        most_saturated = Some((ratio, max_fds));
+                    }
+                }
+            }
+            if let Some(smaps) = process.smaps() {
+                let sum = sum_smaps.get_or_insert_default();
+                sum.pss += smaps.pss;
+                sum.uss += smaps.uss;
+                sum.swap += smaps.swap;
+                sum.shared += smaps.shared;
+            } else {
+                all_have_smaps = false;
+            }
+            if let Some(types) = process.fd_types() {
+                fd_types.get_or_insert_default().add(types);
+            }
+            if let Some(proc_sockets) = process.sockets() {
+                sockets
+                    .get_or_insert_default()
+                    .add(process.pid(), proc_sockets);
             }
             sum_procs += 1;
         }
         metrics.rss = sum_rss_of_procs;
         metrics.memory_usage_bytes = sum_rss_of_procs;
-        metrics.utime = sum_utime;
-        metrics.stime = sum_stime;
-        metrics.cpu_seconds_total = sum_utime + sum_stime;
+        if let Some(smaps) = sum_smaps {
+            metrics.pss_bytes = Some(smaps.pss);
+            metrics.uss_bytes = Some(smaps.uss);
+            metrics.swap_bytes = Some(smaps.swap);
+            metrics.shared_bytes = Some(smaps.shared);
+            // PSS doesn't count the shared pages multiple times, unlike the sum of RSS.
+            // It is only comparable to RSS when it covers all the processes.
+            if all_have_smaps {
+                metrics.memory_usage_bytes = smaps.pss;
+            }
+        }
+        metrics.set_counters(&sum_counters);
         metrics.num_fds = sum_fds;
+        metrics.fd_utilization_ratio = most_saturated.map(|(ratio, _)| ratio);
+        metrics.max_fds = most_saturated.map(|(_, max_fds)| max_fds);
         metrics.num_procs = sum_procs;
         metrics.num_threads = sum_threads;
-        metrics.io_read_bytes_total = sum_io_read_bytes;
-        metrics.io_write_bytes_total = sum_io_write_bytes;
-        metrics.major_page_faults_total = sum_majflt;
-        metrics.minor_page_faults_total = sum_minflt;
         metrics.start_time = min_start_time;
+        // The fd types and the sockets are only counted when enabled in the metrics config.
+        metrics.series = fd_types.map(FdTypes::into_series).unwrap_or_default();
+        metrics
+            .series
+            .extend(sockets.into_iter().flat_map(SocketCounter::into_series));
+        metrics.series.extend(states.into_series());
         metrics
     }
+
+    /// Sets the counters, either summed over the live processes or kept monotonic across
+    /// process exits by the `ProcessHistory`.
+    pub fn set_counters(&mut self, counters: &ProcCounters) {
+        self.utime = ticks_to_seconds(counters.utime);
+        self.stime = ticks_to_seconds(counters.stime);
+        self.cpu_seconds_total = ticks_to_seconds(counters.utime + counters.stime);
+        self.io_read_bytes_total = counters.io_read_bytes;
+        self.io_write_bytes_total = counters.io_write_bytes;
+        self.io_read_chars_total = counters.io_rchar;
+        self.io_write_chars_total = counters.io_wchar;
+        self.io_read_syscalls_total = counters.io_syscr;
+        self.io_write_syscalls_total = counters.io_syscw;
+        self.io_cancelled_write_bytes_total = counters.io_cancelled_write_bytes;
+        self.major_page_faults_total = counters.majflt;
+        self.minor_page_faults_total = counters.minflt;
+        self.voluntary_context_switches_total = counters.voluntary_ctxt_switches;
+        self.nonvoluntary_context_switches_total = counters.nonvoluntary_ctxt_switches;
+        self.scheduler_wait_seconds_total = nanos_to_seconds(counters.run_delay_ns);
+        self.io_delay_seconds_total = ticks_to_seconds(counters.blkio_ticks);
+    }
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn fd_ratio(fd_count: usize, max_fds: u64) -> f64 {
+    fd_count as f64 / max_fds as f64
 }
 
 impl Named for ProcessMetrics {
     fn name(&self) -> &str {
         &self.name
     }
+
+    fn labels(&self) -> &[(String, String)] {
+        &self.labels
+    }
+}
+
+impl Labeled for ProcessMetrics {
+    type Series = ProcessSeries;
+
+    fn labeled_series(&self) -> &[LabeledSeries<ProcessSeries>] {
+        &self.series
+    }
+}
+
+/// A group of process metrics that is emitted with labels of its own.
+/// The field name of each variant becomes the metric name.
+#[derive(Serialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum ProcessSeries {
+    FdsByType { num_fds_by_type: u64 },
+    Sockets { sockets: u64 },
+    ProcsByState { procs_by_state: u64 },
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::{metadata::METADATA, tests::assert_sample};
+
+    use super::*;
+
+    #[test]
+    fn counters_are_exported_in_seconds() {
+        let ticks_per_second = procfs::ticks_per_second();
+        let mut metrics = ProcessMetrics::default();
+        metrics.set_counters(&ProcCounters {
+            utime: 3 * ticks_per_second,
+            stime: ticks_per_second / 2,
+            run_delay_ns: 1_500_000_000,
+            blkio_ticks: 4 * ticks_per_second,
+            ..ProcCounters::default()
+        });
+
+        #[allow(clippy::explicit_auto_deref)]
+        let text =
+            serde_prom::to_prometheus_text(&metrics, Some("process"), &*METADATA, HashMap::new())
+                .unwrap();
+        assert_sample(&text, "process_utime_seconds_total", 3.0);
+        assert_sample(&text, "process_stime_seconds_total", 0.5);
+        assert_sample(&text, "process_cpu_seconds_total", 3.5);
+        assert_sample(&text, "process_scheduler_wait_seconds_total", 1.5);
+        assert_sample(&text, "process_io_delay_seconds_total", 4.0);
+    }
 }
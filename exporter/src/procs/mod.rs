@@ -1,12 +1,29 @@
+mod counters;
 mod discover;
+mod fds;
 mod metrics;
+mod sched;
+mod smaps;
+mod sockets;
+mod states;
 
+use std::ffi::OsStr;
+
+use cgroups_exporter_config::MetricsConfig;
+pub use counters::{GroupKey, ProcessHistory};
 use derive_getters::Getters;
 pub use discover::discover_procs_metrics;
-pub use metrics::ProcessMetrics;
-use procfs::ProcResult;
+use fds::FdTypes;
+pub use metrics::{PROCESS_METRICS, ProcessMetrics, ProcessSeries};
+use procfs::{
+    ProcResult,
+    process::{FDTarget, Process},
+};
+use sched::SchedStats;
+use smaps::SmapsMemory;
+use sockets::ProcSockets;
 
-use crate::matcher::ProcessMatcher;
+use crate::{filter::MetricFilter, matcher::ProcessMatcher};
 
 /// A wrapper around `procfs::process::Process` for testability and caching. Cloneable.
 
@@ -19,6 +36,11 @@ pub struct Proc {
     stat: procfs::process::Stat,
     io: Option<procfs::process::Io>,
     fd_count: Option<usize>,
+    fd_types: Option<FdTypes>,
+    max_fds: Option<u64>,
+    sockets: Option<ProcSockets>,
+    smaps: Option<SmapsMemory>,
+    sched: Option<SchedStats>,
 }
 
 impl TryFrom<procfs::process::Process> for Proc {
@@ -43,6 +65,11 @@ impl TryFrom<procfs::process::Process> for Proc {
             // These fields will be gathered later once a process passes the matcher.
             io: None,
             fd_count: None,
+            fd_types: None,
+            max_fds: None,
+            sockets: None,
+            smaps: None,
+            sched: None,
         })
     }
 }
@@ -66,6 +93,11 @@ impl Proc {
             stat,
             io,
             fd_count,
+            fd_types: None,
+            max_fds: None,
+            sockets: None,
+            smaps: None,
+            sched: None,
         }
     }
 
@@ -82,9 +114,130 @@ impl Proc {
         &self.stat.comm
     }
 
-    pub fn gather_remaining_info(&mut self) -> ProcResult<()> {
-        self.io = Some(procfs::process::Process::new(self.pid)?.io()?);
-        self.fd_count = Some(procfs::process::Process::new(self.pid)?.fd_count()?);
+    /// Reads the files of the process that the allowed metrics need, see [`ProcReads`].
+    pub fn gather_remaining_info(&mut self, reads: &ProcReads) -> ProcResult<()> {
+        let process = procfs::process::Process::new(self.pid)?;
+        if reads.io {
+            self.io = Some(process.io()?);
+        }
+
+        // Reading the target of each fd costs a readlink, so the fds are only counted unless their
+        // targets are needed. When they are, the fds are counted from the same walk.
+        if reads.fd_types || reads.sockets {
+            let targets: Vec<FDTarget> = process
+                .fd()?
+                .filter_map(|fd| Some(fd.ok()?.target))
+                .collect();
+            self.fd_count = Some(targets.len());
+            if reads.fd_types {
+                self.fd_types = Some(FdTypes::from_targets(&targets));
+            }
+            if reads.sockets {
+                self.sockets = ProcSockets::new(&process, &targets);
+            }
+        } else if reads.fd_count {
+            self.fd_count = Some(process.fd_count()?);
+        }
+        if reads.max_fds {
+            self.max_fds = fds::max_fds(&process);
+        }
+        if reads.sched {
+            self.sched = SchedStats::from_process(&process);
+        }
+        if reads.smaps {
+            self.smaps = SmapsMemory::from_process(&process);
+        }
         Ok(())
     }
 }
+
+/// The files of a process to read on top of its stat, from the metrics config and the include and
+/// exclude filters. Each one costs at least a syscall per process, so it is only read when one of
+/// the metrics it is needed for is allowed.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ProcReads {
+    io: bool,
+    fd_count: bool,
+    fd_types: bool,
+    sockets: bool,
+    max_fds: bool,
+    sched: bool,
+    smaps: bool,
+}
+
+impl ProcReads {
+    pub fn new(metrics_config: &MetricsConfig, filter: &MetricFilter) -> Self {
+        Self {
+            io: filter.allows_any(&[
+                "io_read_bytes_total",
+                "io_write_bytes_total",
+                "io_read_chars_total",
+                "io_write_chars_total",
+                "io_read_syscalls_total",
+                "io_write_syscalls_total",
+                "io_cancelled_write_bytes_total",
+            ]),
+            // The fd limit is only reported for the process with the highest fd utilization.
+            fd_count: filter.allows_any(&["num_fds", "max_fds", "fd_utilization_ratio"]),
+            fd_types: metrics_config.fd_types && filter.allows("num_fds_by_type"),
+            sockets: metrics_config.sockets && filter.allows("sockets"),
+            max_fds: filter.allows_any(&["max_fds", "fd_utilization_ratio"]),
+            sched: filter.allows_any(&[
+                "voluntary_context_switches_total",
+                "nonvoluntary_context_switches_total",
+                "scheduler_wait_seconds_total",
+            ]),
+            smaps: metrics_config.smaps_rollup
+                && filter.allows_any(&[
+                    "pss_bytes",
+                    "uss_bytes",
+                    "swap_bytes",
+                    "shared_bytes",
+                    "memory_usage_bytes",
+                ]),
+        }
+    }
+}
+
+/// Returns the inode of the network namespace of the process, which identifies the namespace.
+pub fn net_namespace(process: &Process) -> Option<u64> {
+    let namespaces = process.namespaces().ok()?;
+    namespaces.0.get(OsStr::new("net")).map(|ns| ns.identifier)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_follow_the_filter() {
+        let metrics_config = MetricsConfig {
+            sockets: true,
+            fd_types: true,
+            smaps_rollup: true,
+            ..Default::default()
+        };
+        let reads = ProcReads::new(&metrics_config, &MetricFilter::default());
+        assert!(reads.io && reads.fd_count && reads.fd_types && reads.sockets);
+        assert!(reads.max_fds && reads.sched && reads.smaps);
+
+        let exclude = [
+            "sockets",
+            "num_fds_by_type",
+            "max_fds",
+            "fd_utilization_ratio",
+            "*_context_switches_total",
+            "scheduler_wait_seconds_total",
+        ]
+        .map(ToString::to_string);
+        let filter = MetricFilter::new(&[], &exclude).unwrap();
+        let reads = ProcReads::new(&metrics_config, &filter);
+        assert!(reads.io && reads.fd_count && reads.smaps);
+        assert!(!reads.fd_types && !reads.sockets && !reads.max_fds && !reads.sched);
+
+        let filter = MetricFilter::new(&["cpu_*".to_string()], &[]).unwrap();
+        let reads = ProcReads::new(&metrics_config, &filter);
+        assert!(!reads.io && !reads.fd_count && !reads.smaps);
+    }
+}
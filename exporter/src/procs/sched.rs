@@ -0,0 +1,25 @@
+use procfs::process::Process;
+
+/// The scheduling counters of a process from `/proc/<pid>/status` and `/proc/<pid>/schedstat`.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SchedStats {
+    pub voluntary_ctxt_switches: u64,
+    pub nonvoluntary_ctxt_switches: u64,
+    /// Time spent waiting on a run queue in nanoseconds. `None` when the kernel is built without
+    /// `CONFIG_SCHED_INFO`.
+    pub run_delay_ns: Option<u64>,
+}
+
+impl SchedStats {
+    pub fn from_process(process: &Process) -> Option<Self> {
+        let status = process.status().ok()?;
+        Some(Self {
+            voluntary_ctxt_switches: status.voluntary_ctxt_switches.unwrap_or_default(),
+            nonvoluntary_ctxt_switches: status.nonvoluntary_ctxt_switches.unwrap_or_default(),
+            run_delay_ns: process
+                .schedstat()
+                .ok()
+                .map(|schedstat| schedstat.run_delay),
+        })
+    }
+}
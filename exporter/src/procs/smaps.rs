@@ -0,0 +1,65 @@
+use std::collections::HashMap;
+
+use procfs::process::Process;
+
+/// The memory of a process from `/proc/<pid>/smaps_rollup`, in bytes.
+/// Reading it walks all the mappings of the process, so it is opt-in.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SmapsMemory {
+    /// Proportional set size: the private pages, plus the shared pages divided by their sharers.
+    pub pss: u64,
+    /// Unique set size: the private pages, which would be freed if the process exited.
+    pub uss: u64,
+    pub swap: u64,
+    pub shared: u64,
+}
+
+impl SmapsMemory {
+    pub fn from_process(process: &Process) -> Option<Self> {
+        let rollup = process.smaps_rollup().ok()?;
+        let map = rollup.memory_map_rollup.0.first()?;
+        Some(Self::from_fields(&map.extension.map))
+    }
+
+    fn from_fields(fields: &HashMap<String, u64>) -> Self {
+        let field = |name: &str| fields.get(name).copied().unwrap_or_default();
+        Self {
+            pss: field("Pss"),
+            uss: field("Private_Clean") + field("Private_Dirty"),
+            swap: field("Swap"),
+            shared: field("Shared_Clean") + field("Shared_Dirty"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn smaps_rollup_fields() {
+        let fields = [
+            ("Rss", 10_240),
+            ("Pss", 6144),
+            ("Shared_Clean", 4096),
+            ("Shared_Dirty", 1024),
+            ("Private_Clean", 2048),
+            ("Private_Dirty", 3072),
+            ("Swap", 512),
+        ]
+        .into_iter()
+        .map(|(name, value)| (name.to_string(), value))
+        .collect();
+        assert_eq!(
+            SmapsMemory::from_fields(&fields),
+            SmapsMemory {
+                pss: 6144,
+                uss: 5120,
+                swap: 512,
+                shared: 5120,
+            }
+        );
+    }
+}
@@ -0,0 +1,273 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use procfs::{
+    net::{TcpNetEntry, TcpState, UdpNetEntry, UdpState},
+    process::{FDTarget, Process},
+};
+
+use crate::{procs::metrics::ProcessSeries, render::LabeledSeries};
+
+const PROTO_LABEL: &str = "proto";
+const STATE_LABEL: &str = "state";
+
+const TCP: &str = "tcp";
+const UDP: &str = "udp";
+const LISTEN: &str = "listen";
+const TIME_WAIT: &str = "time_wait";
+
+/// The TCP states that are always reported, even when there are no sockets in them.
+const TCP_STATES: [&str; 4] = ["established", TIME_WAIT, "close_wait", LISTEN];
+
+/// The sockets owned by a process, identified by their inodes in its network namespace.
+#[derive(Debug, Clone)]
+pub struct ProcSockets {
+    netns: u64,
+    inodes: Vec<u64>,
+}
+
+impl ProcSockets {
+    pub fn new(process: &Process, fd_targets: &[FDTarget]) -> Option<Self> {
+        let netns = super::net_namespace(process)?;
+        let inodes = fd_targets
+            .iter()
+            .filter_map(|target| match target {
+                FDTarget::Socket(inode) => Some(*inode),
+                _ => None,
+            })
+            .collect();
+        Some(Self { netns, inodes })
+    }
+}
+
+/// A TCP or UDP socket of a network namespace.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Socket {
+    proto: &'static str,
+    state: &'static str,
+    local_port: u16,
+}
+
+/// The TCP and UDP sockets of a network namespace.
+#[derive(Default)]
+struct NetnsSockets {
+    by_inode: HashMap<u64, Socket>,
+    /// The number of `TIME_WAIT` sockets by local port. These sockets aren't owned by any process
+    /// anymore, so their inode is 0.
+    time_wait_by_port: HashMap<u16, u64>,
+}
+
+impl NetnsSockets {
+    /// Reads the socket tables of the namespace of the process from `/proc/<pid>/net`.
+    fn read(pid: i32) -> Self {
+        let Ok(process) = Process::new(pid) else {
+            return Self::default();
+        };
+        let tcp = process.tcp().into_iter().chain(process.tcp6()).flatten();
+        let udp = process.udp().into_iter().chain(process.udp6()).flatten();
+        let mut sockets = Self::default();
+        for entry in tcp {
+            sockets.insert(tcp_socket(&entry), entry.inode);
+        }
+        for entry in udp {
+            sockets.insert(udp_socket(&entry), entry.inode);
+        }
+        sockets
+    }
+
+    fn insert(&mut self, socket: Socket, inode: u64) {
+        if socket.state == TIME_WAIT {
+            *self.time_wait_by_port.entry(socket.local_port).or_default() += 1;
+        } else if inode != 0 {
+            self.by_inode.insert(inode, socket);
+        }
+    }
+}
+
+fn tcp_socket(entry: &TcpNetEntry) -> Socket {
+    Socket {
+        proto: TCP,
+        state: tcp_state(&entry.state),
+        local_port: entry.local_address.port(),
+    }
+}
+
+fn udp_socket(entry: &UdpNetEntry) -> Socket {
+    Socket {
+        proto: UDP,
+        state: match entry.state {
+            UdpState::Established => "established",
+            UdpState::Close => "close",
+        },
+        local_port: entry.local_address.port(),
+    }
+}
+
+fn tcp_state(state: &TcpState) -> &'static str {
+    match state {
+        TcpState::Established => "established",
+        TcpState::SynSent => "syn_sent",
+        TcpState::SynRecv => "syn_recv",
+        TcpState::FinWait1 => "fin_wait1",
+        TcpState::FinWait2 => "fin_wait2",
+        TcpState::TimeWait => TIME_WAIT,
+        TcpState::Close => "close",
+        TcpState::CloseWait => "close_wait",
+        TcpState::LastAck => "last_ack",
+        TcpState::Listen => LISTEN,
+        TcpState::Closing => "closing",
+        TcpState::NewSynRecv => "new_syn_recv",
+    }
+}
+
+/// Counts the sockets of a group of processes by protocol and state.
+///
+/// The socket inodes of each process are looked up in the tables of its network namespace, which
+/// are read once per namespace. `TIME_WAIT` sockets don't belong to a process, so they are
+/// attributed to the group with a TCP socket on their local port: the listening port on the
+/// server side, or a port still in use by another connection on the client side.
+#[derive(Default)]
+pub struct SocketCounter {
+    tables: HashMap<u64, NetnsSockets>,
+    /// Sockets shared between processes, e.g. after a fork, are only counted once.
+    seen: HashSet<(u64, u64)>,
+    /// The local ports of the TCP sockets of the group in each namespace.
+    local_ports: HashSet<(u64, u16)>,
+    counts: BTreeMap<(&'static str, &'static str), u64>,
+}
+
+impl SocketCounter {
+    pub fn add(&mut self, pid: i32, sockets: &ProcSockets) {
+        let table = self
+            .tables
+            .entry(sockets.netns)
+            .or_insert_with(|| NetnsSockets::read(pid));
+        for inode in &sockets.inodes {
+            let Some(socket) = table.by_inode.get(inode) else {
+                continue;
+            };
+            if !self.seen.insert((sockets.netns, *inode)) {
+                continue;
+            }
+            *self.counts.entry((socket.proto, socket.state)).or_default() += 1;
+            if socket.proto == TCP {
+                self.local_ports.insert((sockets.netns, socket.local_port));
+            }
+        }
+    }
+
+    pub fn into_series(self) -> Vec<LabeledSeries<ProcessSeries>> {
+        let mut counts = self.counts;
+        for state in TCP_STATES {
+            counts.entry((TCP, state)).or_default();
+        }
+        for (netns, port) in &self.local_ports {
+            let time_wait = self
+                .tables
+                .get(netns)
+                .and_then(|table| table.time_wait_by_port.get(port));
+            if let Some(time_wait) = time_wait {
+                *counts.entry((TCP, TIME_WAIT)).or_default() += time_wait;
+            }
+        }
+
+        counts
+            .into_iter()
+            .map(|((proto, state), count)| LabeledSeries {
+                labels: vec![
+                    (PROTO_LABEL, proto.to_string()),
+                    (STATE_LABEL, state.to_string()),
+                ],
+                metrics: ProcessSeries::Sockets { sockets: count },
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn count(series: &[LabeledSeries<ProcessSeries>], proto: &str, state: &str) -> u64 {
+        series
+            .iter()
+            .filter(|series| series.labels[0].1 == proto && series.labels[1].1 == state)
+            .find_map(|series| match series.metrics {
+                ProcessSeries::Sockets { sockets } => Some(sockets),
+                _ => None,
+            })
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn count_sockets_by_state() {
+        let mut table = NetnsSockets::default();
+        let listen = Socket {
+            proto: TCP,
+            state: LISTEN,
+            local_port: 8080,
+        };
+        let established = Socket {
+            state: "established",
+            ..listen
+        };
+        let time_wait = Socket {
+            state: TIME_WAIT,
+            ..listen
+        };
+        table.insert(listen, 10);
+        table.insert(established, 11);
+        table.insert(time_wait, 0);
+        table.insert(time_wait, 0);
+        // A client connection of the group, and one that it closed on the same local port.
+        let client = Socket {
+            state: "established",
+            local_port: 40_000,
+            ..listen
+        };
+        table.insert(client, 13);
+        table.insert(
+            Socket {
+                state: TIME_WAIT,
+                ..client
+            },
+            0,
+        );
+        // Closed by another group.
+        table.insert(
+            Socket {
+                state: TIME_WAIT,
+                local_port: 50_000,
+                ..listen
+            },
+            0,
+        );
+        table.insert(
+            Socket {
+                proto: UDP,
+                state: "close",
+                local_port: 53,
+            },
+            12,
+        );
+
+        let mut counter = SocketCounter::default();
+        counter.tables.insert(1, table);
+        let sockets = ProcSockets {
+            netns: 1,
+            inodes: vec![10, 11, 12, 13, 99],
+        };
+        counter.add(100, &sockets);
+        // A forked child shares the sockets of its parent.
+        counter.add(101, &sockets);
+
+        let series = counter.into_series();
+        assert_eq!(count(&series, TCP, LISTEN), 1);
+        assert_eq!(count(&series, TCP, "established"), 2);
+        assert_eq!(count(&series, TCP, TIME_WAIT), 3);
+        assert_eq!(count(&series, TCP, "close_wait"), 0);
+        assert_eq!(count(&series, UDP, "close"), 1);
+        assert_eq!(series.len(), 5);
+    }
+}
@@ -0,0 +1,83 @@
+use std::collections::BTreeMap;
+
+use crate::{procs::metrics::ProcessSeries, render::LabeledSeries};
+
+const STATE_LABEL: &str = "state";
+
+pub const ZOMBIE: char = 'Z';
+
+/// The states that are always reported, even when no process is in them: running, sleeping,
+/// uninterruptible disk sleep, zombie, stopped and idle.
+const STATES: [char; 6] = ['R', 'S', 'D', ZOMBIE, 'T', 'I'];
+
+/// Counts a group of processes by the state in `/proc/<pid>/stat`.
+///
+/// Zombies are not listed in `cgroup.procs` on cgroup v2, so they are only seen in process
+/// groups and cgroup v1.
+#[derive(Default)]
+pub struct StateCounter {
+    counts: BTreeMap<char, u64>,
+}
+
+impl StateCounter {
+    pub fn add(&mut self, state: char) {
+        *self.counts.entry(state).or_default() += 1;
+    }
+
+    /// Returns one series per state, labeled with the state letter.
+    pub fn into_series(self) -> Vec<LabeledSeries<ProcessSeries>> {
+        let mut counts = self.counts;
+        for state in STATES {
+            counts.entry(state).or_default();
+        }
+        counts
+            .into_iter()
+            .map(|(state, count)| LabeledSeries {
+                labels: vec![(STATE_LABEL, state.to_string())],
+                metrics: ProcessSeries::ProcsByState {
+                    procs_by_state: count,
+                },
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn count_states() {
+        let mut counter = StateCounter::default();
+        counter.add('S');
+        counter.add('S');
+        counter.add('Z');
+        counter.add('Z');
+        counter.add('t');
+
+        let series: Vec<_> = counter
+            .into_series()
+            .into_iter()
+            .map(|series| match series.metrics {
+                ProcessSeries::ProcsByState { procs_by_state } => {
+                    (series.labels[0].1.clone(), procs_by_state)
+                }
+                _ => unreachable!(),
+            })
+            .collect();
+        assert_eq!(
+            series,
+            [
+                ("D".to_string(), 0),
+                ("I".to_string(), 0),
+                ("R".to_string(), 0),
+                ("S".to_string(), 2),
+                ("T".to_string(), 0),
+                ("Z".to_string(), 2),
+                ("t".to_string(), 1),
+            ]
+        );
+    }
+}
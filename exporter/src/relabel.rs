@@ -0,0 +1,325 @@
+//! Relabeling of rendered series, modelled on Prometheus `relabel_configs`.
+//!
+//! The rules see the labels of a series, including the common labels, and the name of each
+//! metric as `__name__`. Setting `__name__` renames the metric, see `MetricsRenderer::write`.
+
+use std::sync::LazyLock;
+
+use cgroups_exporter_config::{RelabelAction, RelabelConfig};
+use md5::{Digest as _, Md5};
+use regex::Regex;
+
+type Labels = Vec<(String, String)>;
+
+/// The label holding the metric name while relabeling, like in Prometheus.
+pub const METRIC_NAME_LABEL: &str = "__name__";
+
+/// Matches the valid label names. Metric names can also contain colons.
+static LABEL_NAME: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new("^[a-zA-Z_][a-zA-Z0-9_]*$").unwrap());
+static METRIC_NAME: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new("^[a-zA-Z_:][a-zA-Z0-9_:]*$").unwrap());
+
+/// A mirror of `RelabelConfig` but with parsed Regex and no serialization.
+#[derive(Debug, Clone)]
+pub struct RelabelRule {
+    source_labels: Vec<String>,
+    separator: String,
+    regex: Regex,
+    modulus: u64,
+    target_label: String,
+    replacement: String,
+    action: RelabelAction,
+}
+
+impl TryFrom<RelabelConfig> for RelabelRule {
+    type Error = anyhow::Error;
+
+    fn try_from(value: RelabelConfig) -> Result<Self, Self::Error> {
+        // Anchor the regex like Prometheus does.
+        let regex = Regex::new(&format!("^(?:{})$", value.regex))?;
+        let target_label = value.target_label.unwrap_or_default();
+        if matches!(
+            value.action,
+            RelabelAction::Replace | RelabelAction::HashMod
+        ) && target_label.is_empty()
+        {
+            anyhow::bail!("Relabel action {:?} requires a target label", value.action);
+        }
+        // Targets that refer to capture groups are checked once they are expanded.
+        if !target_label.is_empty()
+            && !target_label.contains('$')
+            && !LABEL_NAME.is_match(&target_label)
+        {
+            anyhow::bail!("Invalid target label `{target_label}`");
+        }
+        let modulus = value.modulus.unwrap_or_default();
+        if value.action == RelabelAction::HashMod && modulus == 0 {
+            anyhow::bail!("Relabel action HashMod requires a non-zero modulus");
+        }
+        Ok(Self {
+            source_labels: value.source_labels,
+            separator: value.separator,
+            regex,
+            modulus,
+            target_label,
+            replacement: value.replacement,
+            action: value.action,
+        })
+    }
+}
+
+/// Applies the rules in order. Returns `None` if the series is dropped.
+pub fn relabel(rules: &[RelabelRule], mut labels: Labels) -> Option<Labels> {
+    for rule in rules {
+        labels = rule.apply(labels)?;
+    }
+    Some(labels)
+}
+
+impl RelabelRule {
+    /// Whether the rule reads or writes the metric name, in which case the metrics of a series
+    /// are relabeled one by one.
+    pub fn reads_name(&self) -> bool {
+        match self.action {
+            RelabelAction::LabelMap => {
+                self.regex.is_match(METRIC_NAME_LABEL) || self.replacement == METRIC_NAME_LABEL
+            }
+            RelabelAction::LabelDrop => self.regex.is_match(METRIC_NAME_LABEL),
+            _ => {
+                self.target_label == METRIC_NAME_LABEL
+                    || self
+                        .source_labels
+                        .iter()
+                        .any(|label| label == METRIC_NAME_LABEL)
+            }
+        }
+    }
+
+    fn apply(&self, mut labels: Labels) -> Option<Labels> {
+        let value = self.source_value(&labels);
+        match self.action {
+            RelabelAction::Replace => {
+                if let Some(captures) = self.regex.captures(&value) {
+                    let mut target = String::new();
+                    captures.expand(&self.target_label, &mut target);
+                    let mut replacement = String::new();
+                    captures.expand(&self.replacement, &mut replacement);
+                    if !target.is_empty() {
+                        set_label(&mut labels, target, replacement);
+                    }
+                }
+            }
+            RelabelAction::Keep => {
+                if !self.regex.is_match(&value) {
+                    return None;
+                }
+            }
+            RelabelAction::Drop => {
+                if self.regex.is_match(&value) {
+                    return None;
+                }
+            }
+            RelabelAction::HashMod => {
+                let hash = md5_hash(value.as_bytes()) % self.modulus;
+                set_label(&mut labels, self.target_label.clone(), hash.to_string());
+            }
+            RelabelAction::LabelMap => {
+                let mapped: Labels = labels
+                    .iter()
+                    .filter(|(label, _)| self.regex.is_match(label))
+                    .map(|(label, value)| {
+                        let label = self.regex.replace(label, &self.replacement);
+                        (label.into_owned(), value.clone())
+                    })
+                    .collect();
+                for (label, value) in mapped {
+                    set_label(&mut labels, label, value);
+                }
+            }
+            RelabelAction::LabelDrop => {
+                labels.retain(|(label, _)| !self.regex.is_match(label));
+            }
+        }
+        Some(labels)
+    }
+
+    /// Joins the values of the source labels, using an empty value for missing labels.
+    fn source_value(&self, labels: &[(String, String)]) -> String {
+        self.source_labels
+            .iter()
+            .map(|source| {
+                labels
+                    .iter()
+                    .find(|(label, _)| label == source)
+                    .map_or("", |(_, value)| value.as_str())
+            })
+            .collect::<Vec<_>>()
+            .join(&self.separator)
+    }
+}
+
+/// Sets or replaces a label. An empty value removes the label, like in Prometheus. Invalid label
+/// names, and invalid metric names for `__name__`, are skipped.
+fn set_label(labels: &mut Labels, label: String, value: String) {
+    let valid = if label == METRIC_NAME_LABEL {
+        value.is_empty() || METRIC_NAME.is_match(&value)
+    } else {
+        LABEL_NAME.is_match(&label)
+    };
+    if !valid {
+        return;
+    }
+    let existing = labels.iter().position(|(name, _)| *name == label);
+    match (existing, value.is_empty()) {
+        (Some(index), true) => {
+            labels.remove(index);
+        }
+        (Some(index), false) => labels[index].1 = value,
+        (None, true) => {}
+        (None, false) => labels.push((label, value)),
+    }
+}
+
+/// The hash of `hashmod`, the same as in Prometheus: the last 8 bytes of the MD5 digest as a
+/// big-endian integer, so the shards match the ones Prometheus computes.
+fn md5_hash(bytes: &[u8]) -> u64 {
+    let digest = Md5::digest(bytes);
+    let mut last = [0; 8];
+    last.copy_from_slice(&digest[8..]);
+    u64::from_be_bytes(last)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn rule(action: RelabelAction, source_labels: &[&str], regex: &str) -> RelabelConfig {
+        RelabelConfig {
+            source_labels: source_labels.iter().map(ToString::to_string).collect(),
+            separator: ";".to_string(),
+            regex: regex.to_string(),
+            modulus: None,
+            target_label: None,
+            replacement: "$1".to_string(),
+            action,
+        }
+    }
+
+    fn labels(pairs: &[(&str, &str)]) -> Labels {
+        pairs
+            .iter()
+            .map(|(label, value)| ((*label).to_string(), (*value).to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn relabel_rules() {
+        let rules: Vec<RelabelRule> = [
+            RelabelConfig {
+                target_label: Some("service".to_string()),
+                ..rule(RelabelAction::Replace, &["name"], "(.*)-worker")
+            },
+            RelabelConfig {
+                replacement: "k8s_$1".to_string(),
+                ..rule(RelabelAction::LabelMap, &[], "pod_(.*)")
+            },
+            rule(RelabelAction::LabelDrop, &[], "pod_.*"),
+            RelabelConfig {
+                target_label: Some("shard".to_string()),
+                modulus: Some(4),
+                ..rule(RelabelAction::HashMod, &["name"], "(.*)")
+            },
+            rule(RelabelAction::Drop, &["device"], "loop.*"),
+        ]
+        .into_iter()
+        .map(|config| config.try_into().unwrap())
+        .collect();
+
+        let relabeled = relabel(
+            &rules,
+            labels(&[("name", "billing-worker"), ("pod_namespace", "prod")]),
+        )
+        .unwrap();
+        // The same shard as Prometheus computes for this value.
+        assert_eq!(md5_hash(b"billing-worker"), 10_046_344_530_071_272_964);
+        let shard = "0";
+        assert_eq!(
+            relabeled,
+            labels(&[
+                ("name", "billing-worker"),
+                ("service", "billing"),
+                ("k8s_namespace", "prod"),
+                ("shard", shard),
+            ])
+        );
+        assert_eq!(
+            relabel(&rules, labels(&[("name", "db"), ("device", "loop0")])),
+            None
+        );
+
+        let keep: RelabelRule = rule(RelabelAction::Keep, &["name"], "db|cache")
+            .try_into()
+            .unwrap();
+        assert!(relabel(std::slice::from_ref(&keep), labels(&[("name", "db")])).is_some());
+        assert!(relabel(&[keep], labels(&[("name", "dbx")])).is_none());
+
+        assert!(RelabelRule::try_from(rule(RelabelAction::HashMod, &["name"], "(.*)")).is_err());
+    }
+
+    #[test]
+    fn relabel_by_metric_name() {
+        let drop: RelabelRule = rule(RelabelAction::Drop, &[METRIC_NAME_LABEL], "memory_.*")
+            .try_into()
+            .unwrap();
+        assert!(drop.reads_name());
+        let series = |name: &str| labels(&[("name", "db"), (METRIC_NAME_LABEL, name)]);
+        assert!(relabel(std::slice::from_ref(&drop), series("memory_rss")).is_none());
+        assert!(relabel(std::slice::from_ref(&drop), series("cpu_usage_usec_total")).is_some());
+
+        let keep: RelabelRule = rule(RelabelAction::Keep, &["name"], "db")
+            .try_into()
+            .unwrap();
+        assert!(!keep.reads_name());
+
+        let rename: RelabelRule = RelabelConfig {
+            target_label: Some(METRIC_NAME_LABEL.to_string()),
+            replacement: "db_$1".to_string(),
+            ..rule(RelabelAction::Replace, &[METRIC_NAME_LABEL], "process_(.*)")
+        }
+        .try_into()
+        .unwrap();
+        assert!(rename.reads_name());
+        assert_eq!(
+            relabel(std::slice::from_ref(&rename), series("process_num_fds")),
+            Some(series("db_num_fds"))
+        );
+    }
+
+    #[test]
+    fn invalid_names_are_not_written() {
+        let replace = |target: &str| RelabelConfig {
+            target_label: Some(target.to_string()),
+            ..rule(RelabelAction::Replace, &["name"], "(.*)")
+        };
+        assert!(RelabelRule::try_from(replace("foo-bar")).is_err());
+
+        // `$1` expands to `db-1`, which isn't a valid label name.
+        let expand: RelabelRule = replace("$1").try_into().unwrap();
+        assert_eq!(
+            relabel(&[expand], labels(&[("name", "db-1")])),
+            Some(labels(&[("name", "db-1")]))
+        );
+
+        let rename: RelabelRule = RelabelConfig {
+            replacement: "1-$1".to_string(),
+            ..replace(METRIC_NAME_LABEL)
+        }
+        .try_into()
+        .unwrap();
+        let series = labels(&[("name", "db"), (METRIC_NAME_LABEL, "process_rss")]);
+        assert_eq!(relabel(&[rename], series.clone()), Some(series));
+    }
+}
@@ -1,4 +1,9 @@
-use std::{collections::HashMap, io::Write as _};
+use std::{
+    cell::RefCell,
+    collections::{BTreeSet, HashMap},
+    io::Write as _,
+    sync::Arc,
+};
 
 use anyhow::Context as _;
 use bytes::{BufMut as _, BytesMut};
@@ -7,36 +12,76 @@ use serde_prom::MetricDescriptor;
 
 use cgroups_exporter_config::MetricsConfig;
 
+use crate::{
+    filter::MetricFilter,
+    relabel::{METRIC_NAME_LABEL, RelabelRule, relabel},
+};
+
 const NAME_LABEL: &str = "name";
 
+/// The labels of the series of `Labeled` metrics, e.g. the device of the blkio series.
+pub const SERIES_LABELS: &[&str] = &[
+    "major",
+    "minor",
+    "device",
+    "cpu",
+    "mode",
+    "pagesize",
+    "netns",
+    "interface",
+    "type",
+    "proto",
+    "state",
+];
+
 type Metadata<'s> = HashMap<&'s str, MetricDescriptor<'s>>;
 type Serializer<'s> = serde_prom::PrometheusSerializer<'s>;
+type Labels = Vec<(String, String)>;
+/// The full name of a metric and the name a relabeling rule gave it.
+type Rename = (String, String);
 
 pub struct MetricsRenderer<'s> {
+    /// The labels added to every series. They are merged with the other labels before the
+    /// relabeling rules run, rather than left to the serializers.
     common_labels: Vec<(&'s str, &'s str)>,
     metadata: &'s Metadata<'s>,
-    /// Prometheus serializer for each namespace.
-    serializers: HashMap<Option<String>, Serializer<'s>>,
+    /// Prometheus serializer for each namespace. The metrics renamed by the relabeling rules are
+    /// written by a serializer of their own, whose output is renamed when it is finished.
+    serializers: HashMap<(Option<String>, Option<Rename>), Serializer<'s>>,
 }
 
 impl<'s> MetricsRenderer<'s> {
     pub fn new(common_labels: Vec<(&'s str, &'s str)>, metadata: &'s Metadata<'s>) -> Self {
         Self {
-            common_labels: common_labels.clone(),
+            common_labels,
             metadata,
             serializers: HashMap::from([(
-                None,
-                serde_prom::PrometheusSerializer::new(None::<String>, metadata, common_labels),
+                (None, None),
+                serde_prom::PrometheusSerializer::new(None::<String>, metadata, Vec::new()),
             )]),
         }
     }
 
     pub fn finish(self) -> anyhow::Result<Vec<u8>> {
         let mut writer = BytesMut::new().writer();
-        for (_, serializer) in self.serializers {
-            serializer
-                .finish(&mut writer)
-                .context("Failed to finish serialization")?;
+        for ((_, rename), serializer) in self.serializers {
+            match rename {
+                None => {
+                    serializer
+                        .finish(&mut writer)
+                        .context("Failed to finish serialization")?;
+                }
+                Some((from, to)) => {
+                    let mut output = BytesMut::new().writer();
+                    serializer
+                        .finish(&mut output)
+                        .context("Failed to finish serialization")?;
+                    let output = output.into_inner();
+                    for line in String::from_utf8_lossy(&output).lines() {
+                        writeln!(writer, "{}", rename_line(line, &from, &to))?;
+                    }
+                }
+            }
             writer.write_all(b"\n")?;
         }
         Ok(writer.into_inner().into())
@@ -44,51 +89,163 @@ impl<'s> MetricsRenderer<'s> {
 
     pub fn render(
         &mut self,
-        match_group: MatchGroup<impl Serialize + Named>,
+        match_group: MatchGroup<impl Serialize + Named + Labeled>,
     ) -> anyhow::Result<()> {
         let MatchGroup {
             data,
             metrics_config,
+            rules,
         } = match_group;
-
         for metric in data {
-            let serializer = self.serializer(&metrics_config.namespace);
-            let metric_name = metric.name().to_string();
-            let name_label = metrics_config
-                .label_map
-                .get(NAME_LABEL)
-                .map_or_else(|| NAME_LABEL.to_string(), std::borrow::ToOwned::to_owned);
-            serializer.set_current_labels(vec![(name_label, metric_name)]);
-            metric.serialize(serializer)?;
+            let labels: Labels = std::iter::once((name_label(&metrics_config), metric.name()))
+                .chain(
+                    metric
+                        .labels()
+                        .iter()
+                        .map(|(label, value)| (label, value))
+                        .chain(&metrics_config.labels)
+                        .map(|(label, value)| (label.as_str(), value.as_str())),
+                )
+                .chain(self.common_labels.iter().copied())
+                .map(|(label, value)| (label.to_string(), value.to_string()))
+                .collect();
+            self.write(&rules, &metrics_config.namespace, &metric, labels.clone())?;
+
+            for series in metric.labeled_series() {
+                let mut series_labels = labels.clone();
+                series_labels.extend(
+                    series
+                        .labels
+                        .iter()
+                        .map(|(label, value)| ((*label).to_string(), value.clone())),
+                );
+                self.write(
+                    &rules,
+                    &metrics_config.namespace,
+                    &series.metrics,
+                    series_labels,
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Relabels the series and writes the allowed metrics of the value with the new labels.
+    #[allow(clippy::ref_option)]
+    fn write(
+        &mut self,
+        rules: &SeriesRules,
+        namespace: &Option<String>,
+        value: &impl Serialize,
+        labels: Labels,
+    ) -> anyhow::Result<()> {
+        let metadata = self.metadata;
+        let filter = &rules.filter;
+        if !rules.reads_name {
+            if let Some(mut labels) = relabel(&rules.relabel, labels) {
+                // A label expanded from capture groups can name `__name__` without the rules
+                // reading it. The metric isn't renamed then.
+                labels.retain(|(label, _)| label != METRIC_NAME_LABEL);
+                let serializer = self.serializer(namespace, None);
+                serializer.set_current_labels(labels);
+                if filter.is_empty() {
+                    value.serialize(&mut *serializer)?;
+                } else {
+                    filter.wrap(value, metadata).serialize(&mut *serializer)?;
+                }
+            }
+            return Ok(());
         }
 
+        // The metrics of the value can end up with different labels or names, or be dropped,
+        // depending on their name. A first pass that writes nothing collects the names, then the
+        // metrics are written in one pass per label set and name.
+        let names = RefCell::new(BTreeSet::new());
+        let collect = |name: &str| {
+            names.borrow_mut().insert(name.to_string());
+            false
+        };
+        filter
+            .wrap_selected(value, metadata, &collect)
+            .serialize(&mut *self.serializer(namespace, None))?;
+
+        let mut groups: Vec<(Labels, Option<Rename>, BTreeSet<String>)> = Vec::new();
+        for name in names.into_inner() {
+            let full_name = namespace
+                .as_ref()
+                .map_or_else(|| name.clone(), |ns| format!("{ns}_{name}"));
+            let mut labels = labels.clone();
+            labels.push((METRIC_NAME_LABEL.to_string(), full_name.clone()));
+            let Some(mut labels) = relabel(&rules.relabel, labels) else {
+                continue;
+            };
+            let index = labels
+                .iter()
+                .position(|(label, _)| label == METRIC_NAME_LABEL);
+            let new_name = index.map(|index| labels.remove(index).1);
+            let rename = new_name
+                .filter(|new_name| *new_name != full_name)
+                .map(|new_name| (full_name, new_name));
+            match groups
+                .iter_mut()
+                .find(|(group, group_rename, _)| *group == labels && *group_rename == rename)
+            {
+                Some((_, _, names)) => {
+                    names.insert(name);
+                }
+                None => groups.push((labels, rename, BTreeSet::from([name]))),
+            }
+        }
+        for (labels, rename, names) in groups {
+            let serializer = self.serializer(namespace, rename);
+            serializer.set_current_labels(labels);
+            let select = |name: &str| names.contains(name);
+            filter
+                .wrap_selected(value, metadata, &select)
+                .serialize(&mut *serializer)?;
+        }
         Ok(())
     }
 
     #[allow(clippy::ref_option)]
-    fn serializer(&mut self, namespace: &Option<String>) -> &mut Serializer<'s> {
+    fn serializer(
+        &mut self,
+        namespace: &Option<String>,
+        rename: Option<Rename>,
+    ) -> &mut Serializer<'s> {
         self.serializers
-            .entry(namespace.to_owned())
+            .entry((namespace.to_owned(), rename))
             .or_insert_with(|| {
                 serde_prom::PrometheusSerializer::new(
                     namespace.to_owned(),
                     self.metadata,
-                    self.common_labels.clone(),
+                    Vec::new(),
                 )
             })
     }
 }
 
+/// Returns the label of the name of the group, which `labelMap` can rename.
+pub fn name_label(metrics_config: &MetricsConfig) -> &str {
+    metrics_config
+        .label_map
+        .get(NAME_LABEL)
+        .map_or(NAME_LABEL, String::as_str)
+}
+
 pub struct MatchGroup<T> {
     data: Vec<T>,
     metrics_config: MetricsConfig,
+    rules: Arc<SeriesRules>,
 }
 
 impl<T> MatchGroup<T> {
-    pub fn new(data: Vec<T>, metrics_config: MetricsConfig) -> Self {
+    pub fn new(data: Vec<T>, metrics_config: MetricsConfig, rules: Arc<SeriesRules>) -> Self {
         Self {
             data,
             metrics_config,
+            rules,
         }
     }
 
@@ -96,11 +253,215 @@ impl<T> MatchGroup<T> {
         self.data.push(data);
     }
 
-    pub fn into_parts(self) -> (Vec<T>, MetricsConfig) {
-        (self.data, self.metrics_config)
+    pub fn into_parts(self) -> (Vec<T>, MetricsConfig, Arc<SeriesRules>) {
+        (self.data, self.metrics_config, self.rules)
+    }
+}
+
+/// The rules applied to each series of a match group before it is written.
+#[derive(Debug, Default)]
+pub struct SeriesRules {
+    pub relabel: Vec<RelabelRule>,
+    pub filter: MetricFilter,
+    /// Whether a relabeling rule reads or writes the metric name.
+    reads_name: bool,
+}
+
+/// Renames the metric in a line of the text format: its samples, and its `HELP` and `TYPE`
+/// comments.
+fn rename_line(line: &str, from: &str, to: &str) -> String {
+    for prefix in ["# HELP ", "# TYPE ", ""] {
+        if let Some(rest) = line
+            .strip_prefix(prefix)
+            .and_then(|rest| rest.strip_prefix(from))
+            .filter(|rest| rest.starts_with([' ', '{']))
+        {
+            return format!("{prefix}{to}{rest}");
+        }
+    }
+    line.to_string()
+}
+
+impl TryFrom<&MetricsConfig> for SeriesRules {
+    type Error = anyhow::Error;
+
+    fn try_from(value: &MetricsConfig) -> Result<Self, Self::Error> {
+        let relabel: Vec<RelabelRule> = value
+            .relabel
+            .iter()
+            .cloned()
+            .map(RelabelRule::try_from)
+            .collect::<anyhow::Result<_>>()?;
+        Ok(Self {
+            reads_name: relabel.iter().any(RelabelRule::reads_name),
+            relabel,
+            filter: MetricFilter::new(&value.include, &value.exclude)?,
+        })
     }
 }
 
 pub trait Named {
     fn name(&self) -> &str;
+
+    /// The labels of the item on top of the name label, such as the ones rendered from the
+    /// `labels` of its matcher.
+    fn labels(&self) -> &[(String, String)];
+}
+
+/// Metrics that come with additional series, each one with extra labels on top of the name label.
+/// For example, one series per device or per page size.
+pub trait Labeled {
+    type Series: Serialize;
+
+    fn labeled_series(&self) -> &[LabeledSeries<Self::Series>];
+}
+
+#[derive(Debug, Clone)]
+pub struct LabeledSeries<T> {
+    pub labels: Vec<(&'static str, String)>,
+    pub metrics: T,
+}
+
+#[cfg(test)]
+mod tests {
+    use cgroups_exporter_config::{RelabelAction, RelabelConfig};
+    use pretty_assertions::assert_eq;
+
+    use crate::metadata::METADATA;
+
+    use super::*;
+
+    #[derive(Serialize)]
+    struct TestMetrics {
+        #[serde(skip)]
+        labels: Vec<(String, String)>,
+        rss: u64,
+        num_fds: u64,
+    }
+
+    impl Named for TestMetrics {
+        fn name(&self) -> &str {
+            "db"
+        }
+
+        fn labels(&self) -> &[(String, String)] {
+            &self.labels
+        }
+    }
+
+    impl Labeled for TestMetrics {
+        type Series = ();
+
+        fn labeled_series(&self) -> &[LabeledSeries<()>] {
+            &[]
+        }
+    }
+
+    /// The sample lines of the output, without the `HELP` and `TYPE` comments.
+    fn samples(output: &[u8]) -> Vec<String> {
+        String::from_utf8_lossy(output)
+            .lines()
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn common_labels_are_written_once_and_relabeled() {
+        let metrics_config = MetricsConfig {
+            namespace: Some("process".to_string()),
+            relabel: vec![RelabelConfig {
+                source_labels: vec!["name".to_string()],
+                separator: ";".to_string(),
+                regex: "db".to_string(),
+                modulus: None,
+                target_label: Some("node".to_string()),
+                replacement: "node-2".to_string(),
+                action: RelabelAction::Replace,
+            }],
+            ..Default::default()
+        };
+        let rules = Arc::new(SeriesRules::try_from(&metrics_config).unwrap());
+        let metrics = TestMetrics {
+            labels: vec![("service".to_string(), "billing".to_string())],
+            rss: 1024,
+            num_fds: 7,
+        };
+
+        #[allow(clippy::explicit_auto_deref)]
+        let mut renderer = MetricsRenderer::new(vec![("node", "node-1")], &*METADATA);
+        renderer
+            .render(MatchGroup::new(vec![metrics], metrics_config, rules))
+            .unwrap();
+        let samples = samples(&renderer.finish().unwrap());
+
+        assert_eq!(samples.len(), 2, "{samples:?}");
+        for sample in &samples {
+            assert!(sample.starts_with("process_"), "{sample}");
+            assert_eq!(sample.matches("node=").count(), 1, "{sample}");
+            assert!(sample.contains(r#"node="node-2""#), "{sample}");
+            assert!(sample.contains(r#"name="db""#), "{sample}");
+            assert!(sample.contains(r#"service="billing""#), "{sample}");
+        }
+    }
+
+    #[test]
+    fn relabeling_renames_metrics() {
+        let metrics_config = MetricsConfig {
+            namespace: Some("process".to_string()),
+            relabel: vec![RelabelConfig {
+                source_labels: vec![METRIC_NAME_LABEL.to_string()],
+                separator: ";".to_string(),
+                regex: "process_rss".to_string(),
+                modulus: None,
+                target_label: Some(METRIC_NAME_LABEL.to_string()),
+                replacement: "process_resident_bytes".to_string(),
+                action: RelabelAction::Replace,
+            }],
+            ..Default::default()
+        };
+        let rules = Arc::new(SeriesRules::try_from(&metrics_config).unwrap());
+        let metrics = TestMetrics {
+            labels: vec![],
+            rss: 1024,
+            num_fds: 7,
+        };
+
+        #[allow(clippy::explicit_auto_deref)]
+        let mut renderer = MetricsRenderer::new(vec![], &*METADATA);
+        renderer
+            .render(MatchGroup::new(vec![metrics], metrics_config, rules))
+            .unwrap();
+        let samples = samples(&renderer.finish().unwrap());
+
+        assert_eq!(samples.len(), 2, "{samples:?}");
+        assert!(
+            samples
+                .iter()
+                .any(|sample| sample.starts_with(r#"process_resident_bytes{name="db"}"#)),
+            "{samples:?}"
+        );
+        assert!(
+            samples
+                .iter()
+                .any(|sample| sample.starts_with(r#"process_num_fds{name="db"}"#)),
+            "{samples:?}"
+        );
+    }
+
+    #[test]
+    fn rename_text_lines() {
+        assert_eq!(
+            rename_line("# TYPE process_rss gauge", "process_rss", "rss_bytes"),
+            "# TYPE rss_bytes gauge"
+        );
+        assert_eq!(
+            rename_line(r#"process_rss{name="db"} 1"#, "process_rss", "rss_bytes"),
+            r#"rss_bytes{name="db"} 1"#
+        );
+        assert_eq!(
+            rename_line("process_rss_total 1", "process_rss", "rss_bytes"),
+            "process_rss_total 1"
+        );
+    }
 }
@@ -17,8 +17,12 @@ use tower_http::trace::TraceLayer;
 use tracing::{error, info};
 
 use crate::{
-    cgroups::discover_cgroups_metrics, matcher::MatchableConfig, metadata::METADATA,
-    procs::discover_procs_metrics, render::MetricsRenderer, shell::ShellEvaluator,
+    cgroups::discover_cgroups_metrics,
+    matcher::MatchableConfig,
+    metadata::METADATA,
+    procs::{ProcessHistory, discover_procs_metrics},
+    render::MetricsRenderer,
+    shell::ShellEvaluator,
 };
 
 const TIMEOUT_DURATION: Duration = Duration::from_secs(10);
@@ -67,7 +71,7 @@ where
             TimeoutLayer::new(TIMEOUT_DURATION),
         ))
         .layer(CompressionLayer::new())
-        .with_state((config, evaluator));
+        .with_state((config, evaluator, ProcessHistory::default()));
 
     axum::serve(listener, app)
         .with_graceful_shutdown(shutdown)
@@ -85,13 +89,18 @@ async fn homepage() -> Html<&'static str> {
 }
 
 async fn serve_metrics(
-    State((config, evaluator)): State<(SharedConfig, ShellEvaluator)>,
+    State((config, evaluator, history)): State<(SharedConfig, ShellEvaluator, ProcessHistory)>,
 ) -> Result<Response<Body>, AppError> {
-    #[allow(clippy::explicit_auto_deref)]
-    let mut renderer = MetricsRenderer::new(vec![], &*METADATA);
     let config = config.load();
+    let common_labels = config
+        .labels
+        .iter()
+        .map(|(label, value)| (label.as_str(), value.as_str()))
+        .collect();
+    #[allow(clippy::explicit_auto_deref)]
+    let mut renderer = MetricsRenderer::new(common_labels, &*METADATA);
     let cgroup_metrics_stream = discover_cgroups_metrics(config.cgroups.as_slice(), &evaluator);
-    let proc_metrics_stream = discover_procs_metrics(config.processes.as_slice());
+    let proc_metrics_stream = discover_procs_metrics(config.processes.as_slice(), &history);
 
     tokio::pin!(cgroup_metrics_stream);
     tokio::pin!(proc_metrics_stream);
@@ -0,0 +1,21 @@
+//! Helpers shared by the tests of the modules.
+
+/// Returns the value of the sample of the metric `name` in Prometheus text, ignoring its labels.
+pub fn sample_value(text: &str, name: &str) -> Option<f64> {
+    text.lines()
+        .filter(|line| !line.starts_with('#'))
+        .find(|line| {
+            let end = line.find(['{', ' ']).unwrap_or(line.len());
+            line[..end] == *name
+        })
+        .and_then(|line| line.rsplit(' ').next()?.parse().ok())
+}
+
+/// Asserts that the sample of the metric `name` in Prometheus text has the `expected` value.
+pub fn assert_sample(text: &str, name: &str, expected: f64) {
+    let value = sample_value(text, name).unwrap_or_else(|| panic!("no {name} in:\n{text}"));
+    assert!(
+        (value - expected).abs() < f64::EPSILON,
+        "{name} is {value}, not {expected}"
+    );
+}
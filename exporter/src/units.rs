@@ -0,0 +1,17 @@
+//! Conversions of the values read from procfs and cgroupfs to the base units of Prometheus.
+//! Metrics named `_seconds` must be converted with these, see `metadata::Descriptor::unit`.
+
+#![allow(clippy::cast_precision_loss)]
+
+/// Converts clock ticks from `/proc/<pid>/stat`, which are in `USER_HZ`, to seconds.
+pub fn ticks_to_seconds(ticks: u64) -> f64 {
+    ticks as f64 / procfs::ticks_per_second() as f64
+}
+
+pub fn usec_to_seconds(usec: u64) -> f64 {
+    usec as f64 / 1_000_000.0
+}
+
+pub fn nanos_to_seconds(nanos: u64) -> f64 {
+    nanos as f64 / 1_000_000_000.0
+}